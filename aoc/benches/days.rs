@@ -0,0 +1,60 @@
+//! Criterion benchmarks of the parse step and every part of each day, on its real input.
+//!
+//! Filter with the usual criterion arguments, e.g. `cargo bench -p aoc -- day17/`.
+
+use common::Solution;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::fs;
+use std::hint::black_box;
+
+fn bench<S: Solution>(c: &mut Criterion) {
+    let path = format!(
+        "{}/../day{:02}/data.txt",
+        env!("CARGO_MANIFEST_DIR"),
+        S::DAY
+    );
+    let Ok(input) = fs::read_to_string(&path) else {
+        eprintln!("{}: no input, skipped", path);
+        return;
+    };
+    let parsed = S::parse(&input).unwrap_or_else(|err| panic!("{}", err.render(&path, &input)));
+
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+    group.sample_size(10);
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    if S::PARTS > 1 {
+        group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench::<day01::Day01>,
+    bench::<day02::Day02>,
+    bench::<day03::Day03>,
+    bench::<day04::Day04>,
+    bench::<day05::Day05>,
+    bench::<day06::Day06>,
+    bench::<day07::Day07>,
+    bench::<day08::Day08>,
+    bench::<day09::Day09>,
+    bench::<day10::Day10>,
+    bench::<day11::Day11>,
+    bench::<day12::Day12>,
+    bench::<day13::Day13>,
+    bench::<day14::Day14>,
+    bench::<day15::Day15>,
+    bench::<day16::Day16>,
+    bench::<day17::Day17>,
+    bench::<day18::Day18>,
+    bench::<day19::Day19>,
+    bench::<day20::Day20>,
+    bench::<day21::Day21>,
+    bench::<day22::Day22>,
+    bench::<day23::Day23>,
+    bench::<day24::Day24>,
+    bench::<day25::Day25>,
+);
+criterion_main!(benches);
@@ -0,0 +1,102 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// System allocator that counts the allocations made through it.
+///
+/// The `aoc` binary installs it as its global allocator, `aoc bench` reads the counter
+/// around every run. With the `profile` feature, it also keeps track of the bytes allocated
+/// and of the peak size of the heap, read with a [`Meter`].
+pub struct CountingAlloc;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+static HEAP: AtomicU64 = AtomicU64::new(0);
+static PEAK_HEAP: AtomicU64 = AtomicU64::new(0);
+
+/// Whether the allocator keeps track of bytes, the `profile` feature.
+pub const PROFILE: bool = cfg!(feature = "profile");
+
+fn grow(size: usize) {
+    if PROFILE {
+        ALLOCATED_BYTES.fetch_add(size as u64, Ordering::Relaxed);
+        let heap = HEAP.fetch_add(size as u64, Ordering::Relaxed) + size as u64;
+        PEAK_HEAP.fetch_max(heap, Ordering::Relaxed);
+    }
+}
+
+fn shrink(size: usize) {
+    if PROFILE {
+        HEAP.fetch_sub(size as u64, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        grow(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        grow(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        shrink(layout.size());
+        grow(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        shrink(layout.size());
+        System.dealloc(ptr, layout)
+    }
+}
+
+/// Number of allocations (reallocations included) since the start of the program.
+pub fn allocations() -> u64 {
+    ALLOCATIONS.load(Ordering::Relaxed)
+}
+
+/// Heap usage of a stretch of the program. The byte counts are zero without the `profile`
+/// feature.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// Highest size the heap grew to, over its size at the start.
+    pub peak_heap_bytes: u64,
+    /// Allocations, reallocations included.
+    pub allocations: u64,
+    /// Bytes requested by those allocations, freed or not.
+    pub allocated_bytes: u64,
+}
+
+/// Measures the [`Usage`] of the heap from its creation. The counters are shared by every
+/// thread: meters running at the same time see each other's allocations.
+pub struct Meter {
+    heap: u64,
+    allocations: u64,
+    allocated_bytes: u64,
+}
+
+impl Meter {
+    pub fn start() -> Meter {
+        let heap = HEAP.load(Ordering::Relaxed);
+        PEAK_HEAP.store(heap, Ordering::Relaxed);
+        Meter {
+            heap,
+            allocations: allocations(),
+            allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+        }
+    }
+
+    pub fn usage(&self) -> Usage {
+        Usage {
+            peak_heap_bytes: PEAK_HEAP.load(Ordering::Relaxed).saturating_sub(self.heap),
+            allocations: allocations() - self.allocations,
+            allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - self.allocated_bytes,
+        }
+    }
+}
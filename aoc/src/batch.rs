@@ -0,0 +1,139 @@
+use crate::alloc::{self, Meter, Usage};
+use common::cancel::{self, Token};
+use common::{Day, SolveError, Solved};
+use std::collections::{HashMap, VecDeque};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// One part of a day to solve on its input.
+#[derive(Clone)]
+pub struct Job {
+    pub day: Day,
+    pub part: u8,
+    pub input: Arc<str>,
+}
+
+/// How a [`Job`] ended.
+#[derive(Debug)]
+pub enum Outcome {
+    /// The answer, with the heap usage of the job with the `profile` feature.
+    Solved(Solved, Option<Usage>),
+    Failed(SolveError),
+    /// The solver panicked, with this message.
+    Panicked(String),
+    /// Still running after the timeout. The solver was cancelled, but only stops at its next
+    /// checkpoint, if it has any.
+    TimedOut,
+}
+
+enum Event {
+    Started(usize, Token),
+    Finished(usize, Outcome),
+}
+
+type Queue = Arc<Mutex<VecDeque<(usize, Job)>>>;
+
+/// Solve `jobs` on `workers` threads, handing each outcome to `done` with the index of its job
+/// as soon as it is known. A job running for longer than `timeout` is cancelled and reported as
+/// [`Outcome::TimedOut`], and a new thread takes its place so that a solver without checkpoints
+/// does not hold up the rest of the queue.
+pub fn run(
+    jobs: Vec<Job>,
+    workers: usize,
+    timeout: Option<Duration>,
+    mut done: impl FnMut(usize, Outcome),
+) {
+    let total = jobs.len();
+    let queue: Queue = Arc::new(Mutex::new(jobs.into_iter().enumerate().collect()));
+    let (events, received) = mpsc::channel();
+    for _ in 0..workers.clamp(1, total.max(1)) {
+        spawn_worker(&queue, &events);
+    }
+    let mut running: HashMap<usize, (Token, Instant)> = HashMap::new();
+    let mut resolved = 0;
+    while resolved < total {
+        let deadline = timeout.and_then(|t| running.values().map(|&(_, start)| start + t).min());
+        let event = match deadline {
+            Some(deadline) => {
+                match received.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                    Ok(event) => Some(event),
+                    Err(RecvTimeoutError::Timeout) => None,
+                    Err(RecvTimeoutError::Disconnected) => unreachable!("workers outlive the run"),
+                }
+            }
+            None => Some(received.recv().expect("workers outlive the run")),
+        };
+        match event {
+            Some(Event::Started(index, token)) => {
+                running.insert(index, (token, Instant::now()));
+            }
+            Some(Event::Finished(index, outcome)) => {
+                // Jobs that timed out were already reported
+                if running.remove(&index).is_some() {
+                    resolved += 1;
+                    done(index, outcome);
+                }
+            }
+            None => {
+                let now = Instant::now();
+                let timeout = timeout.unwrap_or_default();
+                let expired: Vec<usize> = running
+                    .iter()
+                    .filter(|(_, &(_, start))| now >= start + timeout)
+                    .map(|(&index, _)| index)
+                    .collect();
+                for index in expired {
+                    let (token, _) = running.remove(&index).unwrap();
+                    token.cancel();
+                    resolved += 1;
+                    done(index, Outcome::TimedOut);
+                    spawn_worker(&queue, &events);
+                }
+            }
+        }
+    }
+}
+
+/// A thread solving queued jobs until there are none left, or until one of them gets cancelled.
+fn spawn_worker(queue: &Queue, events: &Sender<Event>) {
+    let (queue, events) = (Arc::clone(queue), events.clone());
+    thread::spawn(move || loop {
+        let Some((index, job)) = queue.lock().unwrap().pop_front() else {
+            return;
+        };
+        let token = Token::new();
+        if events.send(Event::Started(index, token.clone())).is_err() {
+            return;
+        }
+        let meter = Meter::start();
+        let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+            cancel::run_cancellable(&token, || (job.day.solve)(&job.input, &[job.part]))
+        }));
+        let outcome = match solved {
+            Ok(Ok(Ok(mut answers))) => {
+                let usage = alloc::PROFILE.then(|| meter.usage());
+                Outcome::Solved(answers.remove(0), usage)
+            }
+            Ok(Ok(Err(err))) => Outcome::Failed(err),
+            Ok(Err(cancel::Cancelled)) => Outcome::TimedOut,
+            Err(payload) => Outcome::Panicked(panic_message(payload.as_ref())),
+        };
+        if events.send(Event::Finished(index, outcome)).is_err() || token.is_cancelled() {
+            return;
+        }
+    });
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    match (
+        payload.downcast_ref::<&str>(),
+        payload.downcast_ref::<String>(),
+    ) {
+        (Some(message), _) => message.to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => String::from("unknown panic"),
+    }
+}
@@ -0,0 +1,138 @@
+use crate::alloc;
+use common::{Day, SolveError, Stage};
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+/// How long to keep timing a stage: up to `samples` runs, but no more than `max_time`.
+/// A stage is always run at least once.
+#[derive(Debug, Clone, Copy)]
+pub struct Budget {
+    pub samples: usize,
+    pub max_time: Duration,
+}
+
+/// Timings of one stage of a day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub day: u8,
+    pub stage: Stage,
+    pub samples: usize,
+    pub mean: Duration,
+    pub p95: Duration,
+    /// Mean number of allocations per run.
+    pub allocations: u64,
+}
+
+/// Time the parse step and every part of `day` on `input`.
+pub fn measure(day: &Day, input: &str, budget: &Budget) -> Result<Vec<Measurement>, SolveError> {
+    let mut measurements = Vec::new();
+    (day.measure)(input, &mut |stage, run| {
+        let mut times = Vec::with_capacity(budget.samples);
+        let allocations = alloc::allocations();
+        let start = Instant::now();
+        while times.is_empty()
+            || (times.len() < budget.samples && start.elapsed() < budget.max_time)
+        {
+            let t = Instant::now();
+            run();
+            times.push(t.elapsed());
+        }
+        let allocations = (alloc::allocations() - allocations) / times.len() as u64;
+        times.sort();
+        let p95 = times[(times.len() * 95).div_ceil(100) - 1];
+        let mean = times.iter().sum::<Duration>() / times.len() as u32;
+        measurements.push(Measurement {
+            day: day.number,
+            stage,
+            samples: times.len(),
+            mean,
+            p95,
+            allocations,
+        });
+    })?;
+    Ok(measurements)
+}
+
+/// Mean times of a previous run, saved with [`save_baseline`].
+pub type Baseline = HashMap<(u8, Stage), Duration>;
+
+fn stage_key(stage: Stage) -> String {
+    match stage {
+        Stage::Parse => String::from("parse"),
+        Stage::Part(part) => format!("part{}", part),
+    }
+}
+
+/// One line per measurement: day, stage, mean and p95 in nanoseconds, allocations.
+pub fn save_baseline(measurements: &[Measurement]) -> String {
+    let mut out = String::from("# day stage mean_ns p95_ns allocations\n");
+    for m in measurements {
+        writeln!(
+            out,
+            "{} {} {} {} {}",
+            m.day,
+            stage_key(m.stage),
+            m.mean.as_nanos(),
+            m.p95.as_nanos(),
+            m.allocations
+        )
+        .unwrap();
+    }
+    out
+}
+
+pub fn load_baseline(text: &str) -> Result<Baseline, String> {
+    let mut baseline = Baseline::new();
+    for (index, line) in text.lines().enumerate() {
+        if line.starts_with('#') || line.trim().is_empty() {
+            continue;
+        }
+        let invalid = || format!("line {}: invalid baseline entry `{}`", index + 1, line);
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let [day, stage, mean, ..] = fields[..] else {
+            return Err(invalid());
+        };
+        let day = day.parse::<u8>().map_err(|_| invalid())?;
+        let stage = match stage {
+            "parse" => Stage::Parse,
+            part => Stage::Part(
+                part.strip_prefix("part")
+                    .and_then(|p| p.parse().ok())
+                    .ok_or_else(invalid)?,
+            ),
+        };
+        let mean = mean.parse::<u64>().map_err(|_| invalid())?;
+        baseline.insert((day, stage), Duration::from_nanos(mean));
+    }
+    Ok(baseline)
+}
+
+/// Relative change of the mean time against the baseline, in percent.
+pub fn change(measurement: &Measurement, baseline: &Baseline) -> Option<f64> {
+    let before = baseline.get(&(measurement.day, measurement.stage))?;
+    if before.is_zero() {
+        return None;
+    }
+    Some((measurement.mean.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0)
+}
+
+/// Human friendly rendering of a duration, with 3 significant digits.
+pub fn format_duration(d: Duration) -> String {
+    let ns = d.as_nanos() as f64;
+    let (value, unit) = if ns < 1e3 {
+        (ns, "ns")
+    } else if ns < 1e6 {
+        (ns / 1e3, "µs")
+    } else if ns < 1e9 {
+        (ns / 1e6, "ms")
+    } else {
+        (ns / 1e9, "s")
+    };
+    let decimals = match value {
+        v if v < 10.0 => 2,
+        v if v < 100.0 => 1,
+        _ => 0,
+    };
+    format!("{:.*} {}", decimals, value, unit)
+}
@@ -0,0 +1,80 @@
+use common::Day;
+use std::time::Duration;
+use visualize::Visualizer;
+
+pub mod alloc;
+pub mod batch;
+pub mod bench;
+pub mod report;
+
+/// All the days of the calendar, in order.
+pub fn days() -> Vec<Day> {
+    vec![
+        Day::of::<day01::Day01>(),
+        Day::of::<day02::Day02>(),
+        Day::of::<day03::Day03>(),
+        Day::of::<day04::Day04>(),
+        Day::of::<day05::Day05>(),
+        Day::of::<day06::Day06>(),
+        Day::of::<day07::Day07>(),
+        Day::of::<day08::Day08>(),
+        Day::of::<day09::Day09>(),
+        Day::of::<day10::Day10>(),
+        Day::of::<day11::Day11>(),
+        Day::of::<day12::Day12>(),
+        Day::of::<day13::Day13>(),
+        Day::of::<day14::Day14>(),
+        Day::of::<day15::Day15>(),
+        Day::of::<day16::Day16>(),
+        Day::of::<day17::Day17>(),
+        Day::of::<day18::Day18>(),
+        Day::of::<day19::Day19>(),
+        Day::of::<day20::Day20>(),
+        Day::of::<day21::Day21>(),
+        Day::of::<day22::Day22>(),
+        Day::of::<day23::Day23>(),
+        Day::of::<day24::Day24>(),
+        Day::of::<day25::Day25>(),
+    ]
+}
+
+/// Look up a single day by its number.
+pub fn day(number: u8) -> Option<Day> {
+    days().into_iter().find(|d| d.number == number)
+}
+
+/// The days whose simulation can be replayed with `--visualize`.
+pub fn visualizers() -> Vec<Visualizer> {
+    vec![
+        Visualizer::of::<day09::Day09>(),
+        Visualizer::of::<day14::Day14>(),
+        Visualizer::of::<day17::Day17>(),
+        Visualizer::of::<day23::Day23>(),
+        Visualizer::of::<day24::Day24>(),
+    ]
+}
+
+/// Parse a duration such as `30s`, `500ms`, `2m` or `1.5s`.
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let split = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (value, unit) = s.split_at(split);
+    let value = value
+        .parse::<f64>()
+        .map_err(|_| format!("invalid duration `{}`", s))?;
+    let seconds = match unit {
+        "ns" => value / 1e9,
+        "us" | "µs" => value / 1e6,
+        "ms" => value / 1e3,
+        "s" | "" => value,
+        "m" => value * 60.0,
+        _ => {
+            return Err(format!(
+                "invalid duration unit `{}`, expected ms, s or m",
+                unit
+            ))
+        }
+    };
+    Ok(Duration::from_secs_f64(seconds))
+}
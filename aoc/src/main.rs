@@ -0,0 +1,483 @@
+use aoc::alloc::{self, CountingAlloc, Meter};
+use aoc::batch::{self, Job, Outcome};
+use aoc::bench::{self, Budget};
+use aoc::report::{Format, Report};
+use clap::{Args, Parser, Subcommand};
+use common::{Day, Source};
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[global_allocator]
+static ALLOCATOR: CountingAlloc = CountingAlloc;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Print the solver traces up to this level: error, warn, info, debug or trace
+    #[cfg(feature = "trace")]
+    #[arg(long, global = true, default_value = "warn")]
+    log_level: tracing_subscriber::filter::LevelFilter,
+    /// Format of the traces, printed on the standard error
+    #[cfg(feature = "trace")]
+    #[arg(long, global = true, value_enum, default_value_t = LogFormat::Text)]
+    log_format: LogFormat,
+}
+
+#[cfg(feature = "trace")]
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum LogFormat {
+    Text,
+    /// One JSON object per line, for offline analysis
+    Json,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or the whole calendar with --all
+    Run(RunArgs),
+    /// Time the parse step and every part of one day, or of the whole calendar
+    Bench(BenchArgs),
+    /// Check that a puzzle input meets the assumptions of the solver, without solving it
+    Check(CheckArgs),
+}
+
+#[derive(Args)]
+struct CheckArgs {
+    /// Day of the puzzle (1-25)
+    #[arg(short, long)]
+    day: u8,
+    /// Puzzle input, `-` for the standard input. Defaults to dayNN/data.txt
+    file: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to solve (1-25)
+    #[arg(short, long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+    /// Only solve this part
+    #[arg(short, long)]
+    part: Option<u8>,
+    /// Puzzle inputs, solved in turn: files, `-` for the standard input, or directories
+    /// standing for their `*.txt` files. Defaults to dayNN/data.txt
+    #[arg(short, long, num_args = 1.., conflicts_with = "all")]
+    input: Vec<PathBuf>,
+    /// Solve every day with its default input
+    #[arg(long)]
+    all: bool,
+    /// Number of parts solved at the same time with --all
+    #[arg(short, long, value_name = "N", default_value_t = 1, requires = "all")]
+    jobs: usize,
+    /// Give up on a part still running after this long, such as `30s`, with --all
+    #[arg(long, value_parser = aoc::parse_duration, requires = "all")]
+    timeout: Option<Duration>,
+    /// Print the answers as text, or with their timings and input digest as JSON lines or CSV
+    #[arg(long, value_enum, default_value_t = Format::Text, conflicts_with = "visualize")]
+    format: Format,
+    /// Replay the simulation of the part (1 by default) into a `.gif`, `.png` or `.ppm` file,
+    /// or as ASCII frames in any other file, `-` for the standard output
+    #[arg(long, value_name = "FILE", requires = "day")]
+    visualize: Option<PathBuf>,
+    /// Keep one frame out of N
+    #[arg(long, value_name = "N", default_value_t = 1, requires = "visualize")]
+    every: usize,
+    /// Size in pixels of a cell in images
+    #[arg(long, default_value_t = 4, requires = "visualize")]
+    scale: usize,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Day to benchmark, every day when omitted
+    #[arg(short, long)]
+    day: Option<u8>,
+    /// Number of timed runs of each stage
+    #[arg(short, long, default_value_t = 20)]
+    samples: usize,
+    /// Stop sampling a stage after this long
+    #[arg(long, default_value = "5s", value_parser = aoc::parse_duration)]
+    max_time: Duration,
+    /// Save the results as the new baseline
+    #[arg(long, num_args = 0..=1, default_missing_value = "bench-baseline.txt")]
+    save: Option<PathBuf>,
+    /// Compare with a saved baseline and flag regressions
+    #[arg(long, num_args = 0..=1, default_missing_value = "bench-baseline.txt")]
+    baseline: Option<PathBuf>,
+    /// Slowdown, in percent of the baseline mean, flagged as a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    #[cfg(feature = "trace")]
+    init_tracing(cli.log_level, cli.log_format);
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Bench(args) => run_bench(args),
+        Command::Check(args) => check(args),
+    };
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
+
+/// Send the traces to the standard error, closing spans reporting their duration.
+#[cfg(feature = "trace")]
+fn init_tracing(level: tracing_subscriber::filter::LevelFilter, format: LogFormat) {
+    use std::io::IsTerminal;
+    use tracing_subscriber::fmt::format::FmtSpan;
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_ansi(std::io::stderr().is_terminal())
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(std::io::stderr);
+    match format {
+        LogFormat::Text => subscriber.init(),
+        LogFormat::Json => subscriber.json().init(),
+    }
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    if let (Some(number), Some(out)) = (args.day, &args.visualize) {
+        return visualize(number, &args, out);
+    }
+    if args.all {
+        return run_all(&args);
+    }
+    let days = match args.day {
+        Some(number) => vec![aoc::day(number).ok_or(format!("no solution for day {}", number))?],
+        None => aoc::days(),
+    };
+    let sources = common::sources(&args.input).map_err(|err| err.to_string())?;
+    let grouped = sources.len() > 1;
+    let mut report =
+        Report::new(io::stdout().lock(), args.format, grouped).map_err(stdout_error)?;
+    let mut failed = 0;
+    for day in &days {
+        let default = [Source::new(day.input_path())];
+        let sources = if sources.is_empty() {
+            &default[..]
+        } else {
+            &sources
+        };
+        for source in sources {
+            match run_day(day, source, args.part, &mut report) {
+                Err(err) if grouped => {
+                    eprintln!("error: {}", err);
+                    failed += 1;
+                }
+                result => result?,
+            }
+        }
+    }
+    if failed > 0 {
+        return Err(format!("{} of {} inputs failed", failed, sources.len()));
+    }
+    Ok(())
+}
+
+/// Solve every day on its default input, in parallel, printing the days in order.
+fn run_all(args: &RunArgs) -> Result<(), String> {
+    // The heap counters are global, parts solved side by side would share their peaks
+    if alloc::PROFILE && args.jobs > 1 {
+        return Err("the heap profile needs --jobs 1".to_string());
+    }
+    let mut report = Report::new(io::stdout().lock(), args.format, false).map_err(stdout_error)?;
+    let mut jobs = Vec::new();
+    let mut inputs = Vec::new();
+    for day in aoc::days() {
+        if let Some(p) = args.part.filter(|&p| p == 0 || p > day.parts) {
+            return Err(format!("day {} has no part {}", day.number, p));
+        }
+        let source = Source::new(day.input_path());
+        let input: Arc<str> = source
+            .read()
+            .map_err(|err| format!("{}: {}", source, err))?
+            .into();
+        let parts = match args.part {
+            Some(p) => p..=p,
+            None => 1..=day.parts,
+        };
+        let first = jobs.len();
+        for part in parts {
+            jobs.push(Job {
+                day,
+                part,
+                input: Arc::clone(&input),
+            });
+        }
+        inputs.push((day.number, source, input, first..jobs.len()));
+    }
+    let mut outcomes: Vec<Option<Outcome>> = jobs.iter().map(|_| None).collect();
+    let mut printed = 0;
+    let (mut failed, mut timed_out) = (Vec::new(), Vec::new());
+    let mut written = Ok(());
+    let described: Vec<(u8, u8)> = jobs.iter().map(|j| (j.day.number, j.part)).collect();
+    batch::run(jobs, args.jobs, args.timeout, |index, outcome| {
+        let (day, part) = described[index];
+        match &outcome {
+            Outcome::TimedOut => {
+                eprintln!(
+                    "day {} part {}: timed out after {:?}",
+                    day,
+                    part,
+                    args.timeout.unwrap_or_default()
+                );
+                timed_out.push((day, part));
+            }
+            Outcome::Failed(_) | Outcome::Panicked(_) => failed.push((day, part)),
+            Outcome::Solved(..) => (),
+        }
+        outcomes[index] = Some(outcome);
+        // Print the days whose parts are all known, in calendar order
+        while written.is_ok() && printed < inputs.len() {
+            let (number, source, input, range) = &inputs[printed];
+            if outcomes[range.clone()].iter().any(Option::is_none) {
+                break;
+            }
+            let (mut answers, mut usage) = (Vec::new(), Vec::new());
+            for i in range.clone() {
+                match outcomes[i].take() {
+                    Some(Outcome::Solved(solved, used)) => {
+                        answers.push(solved);
+                        usage.extend(used);
+                    }
+                    Some(Outcome::Failed(err)) => {
+                        eprintln!("error: {}", err.render(&source.to_string(), input));
+                    }
+                    Some(Outcome::Panicked(message)) => {
+                        eprintln!(
+                            "error: day {} part {} panicked: {}",
+                            number, described[i].1, message
+                        );
+                    }
+                    _ => (),
+                }
+            }
+            if !answers.is_empty() {
+                written = report.day(*number, source, input, &answers, &usage);
+            }
+            printed += 1;
+        }
+    });
+    written.map_err(stdout_error)?;
+    let mut errors = Vec::new();
+    if !failed.is_empty() {
+        errors.push(format!(
+            "{} of {} parts failed",
+            failed.len(),
+            described.len()
+        ));
+    }
+    if !timed_out.is_empty() {
+        let parts: Vec<String> = timed_out
+            .iter()
+            .map(|(day, part)| format!("day {} part {}", day, part))
+            .collect();
+        errors.push(format!(
+            "{} of {} parts timed out: {}",
+            timed_out.len(),
+            described.len(),
+            parts.join(", ")
+        ));
+    }
+    match errors.is_empty() {
+        true => Ok(()),
+        false => Err(errors.join(", ")),
+    }
+}
+
+fn stdout_error(err: io::Error) -> String {
+    format!("standard output: {}", err)
+}
+
+fn run_day(
+    day: &Day,
+    source: &Source,
+    part: Option<u8>,
+    report: &mut Report<impl Write>,
+) -> Result<(), String> {
+    if let Some(p) = part.filter(|&p| p == 0 || p > day.parts) {
+        return Err(format!("day {} has no part {}", day.number, p));
+    }
+    let input = source
+        .read()
+        .map_err(|err| format!("{}: {}", source, err))?;
+    let parts: Vec<u8> = match part {
+        Some(p) => vec![p],
+        None => (1..=day.parts).collect(),
+    };
+    let solve = |parts: &[u8]| {
+        (day.solve)(&input, parts).map_err(|err| err.render(&source.to_string(), &input))
+    };
+    let (mut answers, mut usage) = (Vec::new(), Vec::new());
+    if alloc::PROFILE {
+        // One part at a time, each parsing the input again, for a usage per part
+        for &part in &parts {
+            let meter = Meter::start();
+            answers.extend(solve(&[part])?);
+            usage.push(meter.usage());
+        }
+    } else {
+        answers = solve(&parts)?;
+    }
+    report
+        .day(day.number, source, &input, &answers, &usage)
+        .map_err(stdout_error)
+}
+
+fn visualize(number: u8, args: &RunArgs, out: &Path) -> Result<(), String> {
+    let visualizer = aoc::visualizers()
+        .into_iter()
+        .find(|v| v.day == number)
+        .ok_or(format!("no visualization for day {}", number))?;
+    let day = aoc::day(number).ok_or(format!("no solution for day {}", number))?;
+    let source = match &args.input[..] {
+        [] => Source::new(day.input_path()),
+        [path] => Source::new(path),
+        _ => return Err(String::from("--visualize takes a single input")),
+    };
+    let part = args.part.unwrap_or(1);
+    if part == 0 || part > day.parts {
+        return Err(format!("day {} has no part {}", number, part));
+    }
+    let input = source
+        .read()
+        .map_err(|err| format!("{}: {}", source, err))?;
+    let options = visualize::Options {
+        every: args.every,
+        scale: args.scale,
+    };
+    let frames =
+        visualize::record(&visualizer, &input, part, out, &options).map_err(|err| match err {
+            visualize::Error::Parse(err) => err.render(&source.to_string(), &input),
+            visualize::Error::Io(err) => format!("{}: {}", out.display(), err),
+        })?;
+    if out != Path::new("-") {
+        println!(
+            "day {} part {}: {} frames written to {}",
+            number,
+            part,
+            frames,
+            out.display()
+        );
+    }
+    Ok(())
+}
+
+fn check(args: CheckArgs) -> Result<(), String> {
+    let day = aoc::day(args.day).ok_or(format!("no solution for day {}", args.day))?;
+    let source = match &args.file {
+        Some(path) => Source::new(path),
+        None => Source::new(day.input_path()),
+    };
+    let input = source
+        .read()
+        .map_err(|err| format!("{}: {}", source, err))?;
+    let assumptions = (day.check)(&input);
+    if assumptions.is_empty() {
+        println!(
+            "day {}: no assumption beyond what the parser checks",
+            day.number
+        );
+        return Ok(());
+    }
+    let mut broken = 0;
+    for assumption in &assumptions {
+        let verdict = if assumption.holds() { "ok" } else { "FAILED" };
+        println!("{:<6}  {}", verdict, assumption.description);
+        for violation in &assumption.violations {
+            let rendered = violation.render(&source.to_string(), &input);
+            println!("{}\n", rendered.trim_end());
+        }
+        broken += !assumption.holds() as usize;
+    }
+    if broken > 0 {
+        return Err(format!(
+            "{} of {} assumptions do not hold for {}",
+            broken,
+            assumptions.len(),
+            source
+        ));
+    }
+    Ok(())
+}
+
+fn run_bench(args: BenchArgs) -> Result<(), String> {
+    let days = match args.day {
+        Some(number) => vec![aoc::day(number).ok_or(format!("no solution for day {}", number))?],
+        None => aoc::days(),
+    };
+    let baseline = match &args.baseline {
+        Some(path) => {
+            let text =
+                fs::read_to_string(path).map_err(|err| format!("{}: {}", path.display(), err))?;
+            Some(
+                bench::load_baseline(&text)
+                    .map_err(|err| format!("{}: {}", path.display(), err))?,
+            )
+        }
+        None => None,
+    };
+    let budget = Budget {
+        samples: args.samples.max(1),
+        max_time: args.max_time,
+    };
+    println!(
+        "{:>3}  {:<6}  {:>7}  {:>10}  {:>10}  {:>11}  vs baseline",
+        "day", "stage", "samples", "mean", "p95", "allocations"
+    );
+    let mut measurements = Vec::new();
+    let mut regressions = 0;
+    for day in &days {
+        let path = PathBuf::from(day.input_path());
+        let input =
+            fs::read_to_string(&path).map_err(|err| format!("{}: {}", path.display(), err))?;
+        let results = bench::measure(day, &input, &budget)
+            .map_err(|err| err.render(&path.display().to_string(), &input))?;
+        for m in results {
+            let change = baseline.as_ref().and_then(|b| bench::change(&m, b));
+            let verdict = match change {
+                Some(c) if c > args.threshold => {
+                    regressions += 1;
+                    format!("{:+.1}% REGRESSION", c)
+                }
+                Some(c) => format!("{:+.1}%", c),
+                None => String::new(),
+            };
+            let row = format!(
+                "{:>3}  {:<6}  {:>7}  {:>10}  {:>10}  {:>11}  {}",
+                m.day,
+                m.stage.to_string(),
+                m.samples,
+                bench::format_duration(m.mean),
+                bench::format_duration(m.p95),
+                m.allocations,
+                verdict
+            );
+            println!("{}", row.trim_end());
+            measurements.push(m);
+        }
+    }
+    if let Some(path) = &args.save {
+        fs::write(path, bench::save_baseline(&measurements))
+            .map_err(|err| format!("{}: {}", path.display(), err))?;
+        println!("baseline saved to {}", path.display());
+    }
+    if regressions > 0 {
+        return Err(format!(
+            "{} regression(s) against the baseline",
+            regressions
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,126 @@
+use crate::alloc::Usage;
+use common::{Answer, Answers, Source};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::io::{self, Write};
+
+/// How `aoc run` prints the answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// `part 1: ...` lines under a `day N` header
+    Text,
+    /// One JSON object per part and per line
+    Json,
+    /// A header, then one row per part
+    Csv,
+}
+
+/// Columns of the JSON and CSV reports.
+const FIELDS: [&str; 6] = [
+    "day",
+    "input",
+    "part",
+    "answer",
+    "elapsed_ns",
+    "input_sha256",
+];
+
+/// Answers of the days, written as they are solved.
+pub struct Report<W: Write> {
+    out: W,
+    format: Format,
+    /// Name the input in the text headers, when several are solved.
+    grouped: bool,
+}
+
+impl<W: Write> Report<W> {
+    pub fn new(mut out: W, format: Format, grouped: bool) -> io::Result<Report<W>> {
+        if format == Format::Csv {
+            writeln!(out, "{}", FIELDS.join(","))?;
+        }
+        Ok(Report {
+            out,
+            format,
+            grouped,
+        })
+    }
+
+    /// Write the answers of `day` on `input`, read from `source`. The heap usage of each
+    /// answer, if profiled, goes along in the JSON records.
+    pub fn day(
+        &mut self,
+        day: u8,
+        source: &Source,
+        input: &str,
+        answers: &Answers,
+        usage: &[Usage],
+    ) -> io::Result<()> {
+        if self.format == Format::Text {
+            match self.grouped {
+                true => writeln!(self.out, "day {}, {}", day, source)?,
+                false => writeln!(self.out, "day {}", day)?,
+            }
+            for solved in answers {
+                common::write_answer(&mut self.out, solved.part, &solved.answer)?;
+            }
+            return self.out.flush();
+        }
+        let sha256 = sha256(input);
+        for (i, solved) in answers.iter().enumerate() {
+            let elapsed_ns = solved.elapsed.as_nanos() as u64;
+            match self.format {
+                Format::Json => {
+                    let answer = answer_json(&solved.answer);
+                    let mut record = json!({
+                        "day": day,
+                        "input": source.to_string(),
+                        "part": solved.part,
+                        "answer": answer,
+                        "elapsed_ns": elapsed_ns,
+                        "input_sha256": sha256,
+                    });
+                    if let Some(usage) = usage.get(i) {
+                        record["peak_heap_bytes"] = json!(usage.peak_heap_bytes);
+                        record["allocations"] = json!(usage.allocations);
+                        record["allocated_bytes"] = json!(usage.allocated_bytes);
+                    }
+                    writeln!(self.out, "{}", record)?;
+                }
+                _ => writeln!(
+                    self.out,
+                    "{},{},{},{},{},{}",
+                    day,
+                    csv_field(&source.to_string()),
+                    solved.part,
+                    csv_field(&solved.answer.to_string()),
+                    elapsed_ns,
+                    sha256
+                )?,
+            }
+        }
+        self.out.flush()
+    }
+}
+
+/// An answer as a JSON number, or a string for the text answers.
+pub fn answer_json(answer: &Answer) -> Value {
+    match answer {
+        Answer::Int(v) => json!(v),
+        Answer::UInt(v) => json!(v),
+        Answer::Text(v) => json!(v),
+    }
+}
+
+/// Hex digest of the input, telling which input an answer belongs to.
+fn sha256(input: &str) -> String {
+    format!("{:x}", Sha256::digest(input.as_bytes()))
+}
+
+/// Quote a field holding a separator, a quote or a line break (such as the day 10 CRT).
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
@@ -0,0 +1,24 @@
+use aoc::alloc::{CountingAlloc, Meter, PROFILE};
+use std::hint::black_box;
+
+#[global_allocator]
+static ALLOCATOR: CountingAlloc = CountingAlloc;
+
+#[test]
+fn meter_reads_the_heap_usage() {
+    let meter = Meter::start();
+    let big = black_box(vec![0u8; 1 << 20]);
+    drop(big);
+    let small = black_box(vec![0u8; 1 << 10]);
+    let usage = meter.usage();
+    drop(small);
+    assert!(usage.allocations >= 2);
+    if PROFILE {
+        // The peak is when the large vector was alive
+        assert!(usage.peak_heap_bytes >= 1 << 20);
+        assert!(usage.peak_heap_bytes < (1 << 20) + (1 << 10) + 4096);
+        assert!(usage.allocated_bytes >= (1 << 20) + (1 << 10));
+    } else {
+        assert_eq!((usage.peak_heap_bytes, usage.allocated_bytes), (0, 0));
+    }
+}
@@ -0,0 +1,66 @@
+use aoc::batch::{self, Job, Outcome};
+use common::{Answer, Day, ParseError, Solution};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Part 1 returns at once, part 2 never does but checks for cancellation.
+struct Endless;
+
+impl Solution for Endless {
+    const DAY: u8 = 1;
+
+    type Input = ();
+
+    fn parse(_input: &str) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    fn part1(_input: &()) -> Answer {
+        42u64.into()
+    }
+
+    fn part2(_input: &()) -> Answer {
+        loop {
+            common::cancel::checkpoint();
+            std::thread::yield_now();
+        }
+    }
+}
+
+fn run(parts: &[u8], workers: usize) -> Vec<(usize, Outcome)> {
+    let input: Arc<str> = Arc::from("");
+    let jobs = parts
+        .iter()
+        .map(|&part| Job {
+            day: Day::of::<Endless>(),
+            part,
+            input: Arc::clone(&input),
+        })
+        .collect();
+    let mut outcomes = Vec::new();
+    batch::run(jobs, workers, Some(Duration::from_millis(100)), |i, o| {
+        outcomes.push((i, o))
+    });
+    outcomes.sort_by_key(|&(i, _)| i);
+    outcomes
+}
+
+#[test]
+fn slow_parts_time_out_and_the_others_are_solved() {
+    let outcomes = run(&[2, 1, 2, 1], 2);
+    assert_eq!(outcomes.len(), 4);
+    for (i, outcome) in outcomes {
+        match (i % 2, outcome) {
+            (0, Outcome::TimedOut) => (),
+            (1, Outcome::Solved(solved, _)) => assert_eq!(solved.answer, Answer::UInt(42)),
+            (_, outcome) => panic!("job {}: {:?}", i, outcome),
+        }
+    }
+}
+
+#[test]
+fn a_timed_out_worker_is_replaced() {
+    // A single worker: the part 1 jobs only run once the part 2 ones were cancelled
+    let outcomes = run(&[2, 2, 1], 1);
+    assert!(matches!(outcomes[2].1, Outcome::Solved(..)));
+}
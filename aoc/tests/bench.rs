@@ -0,0 +1,98 @@
+use aoc::bench::{self, Baseline, Measurement};
+use common::Stage;
+use std::time::Duration;
+
+fn measurement(day: u8, stage: Stage, mean_ns: u64) -> Measurement {
+    Measurement {
+        day,
+        stage,
+        samples: 10,
+        mean: Duration::from_nanos(mean_ns),
+        p95: Duration::from_nanos(mean_ns * 2),
+        allocations: 7,
+    }
+}
+
+#[test]
+fn baseline_round_trip() {
+    let measurements = [
+        measurement(1, Stage::Parse, 1500),
+        measurement(1, Stage::Part(1), 20_000),
+        measurement(25, Stage::Part(2), 3_000_000_000),
+    ];
+    let text = bench::save_baseline(&measurements);
+    assert!(text.starts_with("# day stage mean_ns p95_ns allocations\n"));
+    assert!(text.contains("\n1 part1 20000 40000 7\n"));
+    let baseline = bench::load_baseline(&text).unwrap();
+    assert_eq!(
+        baseline,
+        Baseline::from([
+            ((1, Stage::Parse), Duration::from_nanos(1500)),
+            ((1, Stage::Part(1)), Duration::from_nanos(20_000)),
+            ((25, Stage::Part(2)), Duration::from_secs(3)),
+        ])
+    );
+    // Blank lines and comments are skipped, trailing fields ignored
+    let baseline = bench::load_baseline("\n# comment\n3 part2 100\n").unwrap();
+    assert_eq!(baseline[&(3, Stage::Part(2))], Duration::from_nanos(100));
+}
+
+#[test]
+fn malformed_baseline() {
+    for (text, line) in [
+        ("1 parse\n", "1 parse"),
+        ("1 parse 10\n256 parse 10\n", "256 parse 10"),
+        ("1 part 10\n", "1 part 10"),
+        ("1 total 10\n", "1 total 10"),
+        ("1 part1 -10\n", "1 part1 -10"),
+    ] {
+        let number = text.lines().position(|l| l == line).unwrap() + 1;
+        assert_eq!(
+            bench::load_baseline(text),
+            Err(format!(
+                "line {}: invalid baseline entry `{}`",
+                number, line
+            ))
+        );
+    }
+}
+
+#[test]
+fn regressions() {
+    let baseline = Baseline::from([
+        ((1, Stage::Parse), Duration::from_micros(100)),
+        ((1, Stage::Part(1)), Duration::ZERO),
+    ]);
+    // In percent, rounded off the floating point noise
+    let change = |stage, mean_ns| {
+        bench::change(&measurement(1, stage, mean_ns), &baseline).map(|c| (c * 1e6).round() / 1e6)
+    };
+    assert_eq!(change(Stage::Parse, 150_000), Some(50.0));
+    assert_eq!(change(Stage::Parse, 75_000), Some(-25.0));
+    assert_eq!(change(Stage::Parse, 100_000), Some(0.0));
+    // Nothing to compare to
+    assert_eq!(change(Stage::Part(1), 1000), None);
+    assert_eq!(change(Stage::Part(2), 1000), None);
+}
+
+#[test]
+fn durations() {
+    assert_eq!(aoc::parse_duration("30s"), Ok(Duration::from_secs(30)));
+    assert_eq!(aoc::parse_duration("30"), Ok(Duration::from_secs(30)));
+    assert_eq!(aoc::parse_duration("1.5s"), Ok(Duration::from_millis(1500)));
+    assert_eq!(aoc::parse_duration("500ms"), Ok(Duration::from_millis(500)));
+    assert_eq!(aoc::parse_duration("250us"), Ok(Duration::from_micros(250)));
+    assert_eq!(aoc::parse_duration("2m"), Ok(Duration::from_secs(120)));
+    assert_eq!(
+        aoc::parse_duration("5h"),
+        Err(String::from(
+            "invalid duration unit `h`, expected ms, s or m"
+        ))
+    );
+    for invalid in ["", "s", "-1s", "1.2.3s"] {
+        assert_eq!(
+            aoc::parse_duration(invalid),
+            Err(format!("invalid duration `{}`", invalid))
+        );
+    }
+}
@@ -0,0 +1,41 @@
+//! The real inputs meet the assumptions of their solver, and the examples of day 22, laid
+//! out differently, do not.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn input_file(day: u8, file: &str) -> PathBuf {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    root.join(format!("day{:02}", day)).join(file)
+}
+
+#[test]
+fn real_inputs_meet_the_assumptions() {
+    for day in aoc::days() {
+        let Ok(input) = fs::read_to_string(input_file(day.number, "data.txt")) else {
+            continue;
+        };
+        for assumption in (day.check)(&input) {
+            assert!(
+                assumption.holds(),
+                "day {}: {}: {:?}",
+                day.number,
+                assumption.description,
+                assumption.violations
+            );
+        }
+    }
+}
+
+#[test]
+fn day22_example_is_not_the_expected_net() {
+    let input = fs::read_to_string(input_file(22, "data.test.txt")).unwrap();
+    let assumptions = (aoc::day(22).unwrap().check)(&input);
+    assert_eq!(assumptions.len(), 1);
+    let lines: Vec<usize> = assumptions[0]
+        .violations
+        .iter()
+        .map(|e| e.line_no)
+        .collect();
+    assert_eq!(lines, [1, 15]);
+}
@@ -0,0 +1,333 @@
+//! Differential tests: the shortcuts of a few days are checked against naive reference
+//! solvers on small random inputs. The generators build a model of the puzzle, rendered as
+//! text for the day's parser and solved directly by the reference.
+
+use common::Solution;
+use num_bigint_dig::BigUint;
+use proptest::prelude::*;
+use std::collections::HashSet;
+
+/// A monkey of day 11, `operand` being `None` for `old`.
+#[derive(Debug, Clone)]
+struct Monkey {
+    items: Vec<u64>,
+    multiply: bool,
+    operand: Option<u64>,
+    divisor: u64,
+    if_true: usize,
+    if_false: usize,
+}
+
+fn monkeys() -> impl Strategy<Value = Vec<Monkey>> {
+    (2..=4usize).prop_flat_map(|count| {
+        let monkey = (
+            prop::collection::vec(0..100u64, 0..4),
+            any::<bool>(),
+            prop::option::of(1..20u64),
+            1..=23u64,
+            0..count - 1,
+            0..count - 1,
+        );
+        prop::collection::vec(monkey, count).prop_map(|monkeys| {
+            monkeys
+                .into_iter()
+                .enumerate()
+                .map(
+                    |(i, (items, multiply, operand, divisor, if_true, if_false))| Monkey {
+                        items,
+                        multiply,
+                        operand,
+                        divisor,
+                        // Skip over the monkey itself
+                        if_true: if_true + (if_true >= i) as usize,
+                        if_false: if_false + (if_false >= i) as usize,
+                    },
+                )
+                .collect()
+        })
+    })
+}
+
+fn render_monkeys(monkeys: &[Monkey]) -> String {
+    let mut out = String::new();
+    for (i, m) in monkeys.iter().enumerate() {
+        let items: Vec<String> = m.items.iter().map(u64::to_string).collect();
+        let operand = m.operand.map_or(String::from("old"), |v| v.to_string());
+        let op = if m.multiply { '*' } else { '+' };
+        out += &format!("Monkey {}:\n", i);
+        out += &format!("  Starting items: {}\n", items.join(", "));
+        out += &format!("  Operation: new = old {} {}\n", op, operand);
+        out += &format!("  Test: divisible by {}\n", m.divisor);
+        out += &format!("    If true: throw to monkey {}\n", m.if_true);
+        out += &format!("    If false: throw to monkey {}\n\n", m.if_false);
+    }
+    out
+}
+
+/// Day 11 without relief nor modulus: worry levels grow as big as they need.
+fn naive_business(monkeys: &[Monkey], rounds: u32) -> u64 {
+    let mut items: Vec<Vec<BigUint>> = monkeys
+        .iter()
+        .map(|m| m.items.iter().map(|&i| BigUint::from(i)).collect())
+        .collect();
+    let mut inspected = vec![0u64; monkeys.len()];
+    for _ in 0..rounds {
+        for (i, m) in monkeys.iter().enumerate() {
+            for old in std::mem::take(&mut items[i]) {
+                let operand = m.operand.map_or(old.clone(), BigUint::from);
+                let new = if m.multiply {
+                    &old * operand
+                } else {
+                    &old + operand
+                };
+                let target = match (&new % m.divisor) == BigUint::from(0u64) {
+                    true => m.if_true,
+                    false => m.if_false,
+                };
+                items[target].push(new);
+                inspected[i] += 1;
+            }
+        }
+    }
+    inspected.sort_unstable_by(|a, b| b.cmp(a));
+    inspected[0] * inspected[1]
+}
+
+/// Day 17, dropping every rock one cell at a time in a set of cells.
+fn naive_tower_height(jet: &str, rocks: u64) -> u64 {
+    const SHAPES: [&[(i64, i64)]; 5] = [
+        &[(0, 0), (1, 0), (2, 0), (3, 0)],
+        &[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+        &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+        &[(0, 0), (0, 1), (0, 2), (0, 3)],
+        &[(0, 0), (1, 0), (0, 1), (1, 1)],
+    ];
+    let jet: Vec<char> = jet.chars().collect();
+    let mut cells = HashSet::new();
+    let mut height = 0;
+    let mut clock = 0;
+    for rock in 0..rocks as usize {
+        let shape = SHAPES[rock % SHAPES.len()];
+        let fits = |cells: &HashSet<(i64, i64)>, x: i64, y: i64| {
+            shape.iter().all(|&(dx, dy)| {
+                (0..7).contains(&(x + dx)) && y + dy > 0 && !cells.contains(&(x + dx, y + dy))
+            })
+        };
+        let (mut x, mut y) = (2, height + 4);
+        loop {
+            let dx = if jet[clock % jet.len()] == '<' { -1 } else { 1 };
+            clock += 1;
+            if fits(&cells, x + dx, y) {
+                x += dx;
+            }
+            if !fits(&cells, x, y - 1) {
+                break;
+            }
+            y -= 1;
+        }
+        for &(dx, dy) in shape {
+            cells.insert((x + dx, y + dy));
+            height = height.max(y + dy);
+        }
+    }
+    height as u64
+}
+
+/// A sensor of day 15 and its closest beacon.
+type Sensor = ((i64, i64), (i64, i64));
+
+fn sensors() -> impl Strategy<Value = Vec<Sensor>> {
+    let point = || (-5..=25i64, -5..=25i64);
+    prop::collection::vec((point(), point()), 1..8)
+}
+
+fn render_sensors(sensors: &[Sensor]) -> String {
+    let mut out = String::from("10\n");
+    for ((sx, sy), (bx, by)) in sensors {
+        out += &format!(
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+            sx, sy, bx, by
+        );
+    }
+    out
+}
+
+/// Day 15, trying every position of the square in turn.
+fn naive_find_beacon(sensors: &[Sensor], size: i64) -> Option<(i64, i64)> {
+    let distance = |(x0, y0): (i64, i64), (x1, y1): (i64, i64)| (x0 - x1).abs() + (y0 - y1).abs();
+    (0..=size)
+        .flat_map(|y| (0..=size).map(move |x| (x, y)))
+        .find(|&p| {
+            sensors
+                .iter()
+                .all(|&(s, b)| distance(p, s) > distance(b, s))
+        })
+}
+
+/// An expression of day 21, with the human somewhere among its leaves.
+#[derive(Debug, Clone)]
+enum Expr {
+    Human,
+    Number(i64),
+    Op(char, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    fn op(op: char, lhs: Expr, rhs: Expr) -> Expr {
+        Expr::Op(op, Box::new(lhs), Box::new(rhs))
+    }
+
+    /// Value when the human yells `human`, `None` when a division is not exact or by zero.
+    fn eval(&self, human: i64) -> Option<i64> {
+        match self {
+            Expr::Human => Some(human),
+            Expr::Number(n) => Some(*n),
+            Expr::Op(op, lhs, rhs) => {
+                let (l, r) = (lhs.eval(human)?, rhs.eval(human)?);
+                match op {
+                    '+' => l.checked_add(r),
+                    '-' => l.checked_sub(r),
+                    '*' => l.checked_mul(r),
+                    _ => (r != 0 && l % r == 0).then(|| l / r),
+                }
+            }
+        }
+    }
+}
+
+/// A small expression without the human, its divisions exact.
+fn constant() -> impl Strategy<Value = Expr> {
+    let leaf = (1..10i64).prop_map(Expr::Number);
+    leaf.prop_recursive(2, 4, 2, |inner| {
+        prop_oneof![
+            (
+                prop::sample::select(vec!['+', '-', '*']),
+                inner.clone(),
+                inner
+            )
+                .prop_map(|(op, l, r)| Expr::op(op, l, r)),
+            (1..10i64, 1..10i64).prop_map(|(q, d)| Expr::op(
+                '/',
+                Expr::Number(q * d),
+                Expr::Number(d)
+            )),
+        ]
+    })
+}
+
+/// The two sides of `root`: a chain of operations from the human yelling `human` up to the
+/// root, and a constant side equal to it. Like the real inputs, the human is never a divisor
+/// and is never multiplied by zero, so `human` is the only solution.
+fn equation() -> impl Strategy<Value = (i64, Expr, Expr)> {
+    let step = (
+        prop::sample::select(vec!['+', '-', '*', '/']),
+        any::<bool>(),
+        constant(),
+    );
+    (
+        -50..=50i64,
+        prop::collection::vec(step, 0..6),
+        any::<bool>(),
+    )
+        .prop_filter_map("overflow", |(human, steps, swap)| {
+            let mut side = Expr::Human;
+            for (op, left, c) in steps {
+                let (value, constant) = (side.eval(human)?, c.eval(human)?);
+                side = match op {
+                    '*' if constant == 0 => continue,
+                    '/' if constant == 0 || value % constant != 0 => continue,
+                    '/' => Expr::op('/', side, c),
+                    op if left => Expr::op(op, c, side),
+                    op => Expr::op(op, side, c),
+                };
+            }
+            let other = Expr::Number(side.eval(human)?);
+            Some(match swap {
+                true => (human, other, side),
+                false => (human, side, other),
+            })
+        })
+}
+
+fn render_monkey_jobs(lhs: &Expr, rhs: &Expr) -> String {
+    /// Name of the `n`th monkey, out of letters that cannot spell `root` nor `humn`.
+    fn name(n: usize) -> String {
+        (0..4)
+            .map(|i| (b'a' + (n / 10usize.pow(i) % 10) as u8) as char)
+            .collect()
+    }
+    fn monkey(expr: &Expr, jobs: &mut Vec<String>) -> String {
+        let id = match expr {
+            Expr::Human => String::from("humn"),
+            _ => name(jobs.len()),
+        };
+        let job = match expr {
+            Expr::Human => String::from("5"),
+            Expr::Number(n) => n.to_string(),
+            Expr::Op(op, lhs, rhs) => {
+                jobs.push(String::new());
+                let slot = jobs.len() - 1;
+                let (l, r) = (monkey(lhs, jobs), monkey(rhs, jobs));
+                jobs[slot] = format!("{}: {} {} {}", id, l, op, r);
+                return id;
+            }
+        };
+        jobs.push(format!("{}: {}", id, job));
+        id
+    }
+    let mut jobs = Vec::new();
+    let (l, r) = (monkey(lhs, &mut jobs), monkey(rhs, &mut jobs));
+    jobs.push(format!("root: {} + {}", l, r));
+    jobs.join("\n") + "\n"
+}
+
+/// Day 21 part 2, trying every number the human could yell around zero.
+fn naive_human(lhs: &Expr, rhs: &Expr) -> Option<i64> {
+    (-1000..=1000).find(|&h| matches!((lhs.eval(h), rhs.eval(h)), (Some(l), Some(r)) if l == r))
+}
+
+proptest! {
+    #[test]
+    fn day11_modulus_keeps_the_divisibility_tests(monkeys in monkeys(), rounds in 1..=3u32) {
+        let input = day11::Day11::parse(&render_monkeys(&monkeys)).unwrap();
+        prop_assert_eq!(
+            day11::anxious_business(&input, rounds),
+            naive_business(&monkeys, rounds)
+        );
+    }
+
+    #[test]
+    fn day15_row_skipping_finds_the_first_free_position(
+        sensors in sensors(),
+        size in 0..=20i64
+    ) {
+        let input = day15::Day15::parse(&render_sensors(&sensors)).unwrap();
+        prop_assert_eq!(
+            day15::find_beacon(&input, size),
+            naive_find_beacon(&sensors, size)
+        );
+    }
+
+    #[test]
+    fn day21_inversion_finds_the_human_number((human, lhs, rhs) in equation()) {
+        let input = day21::Day21::parse(&render_monkey_jobs(&lhs, &rhs)).unwrap();
+        prop_assert_eq!(naive_human(&lhs, &rhs), Some(human));
+        prop_assert_eq!(day21::Day21::part2(&input), human.into());
+    }
+}
+
+proptest! {
+    // Each case drops up to two thousand rocks, twice
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn day17_cycle_detection_matches_the_simulation(
+        jet in "[<>]{1,30}",
+        rocks in 1..2000u64
+    ) {
+        prop_assert_eq!(
+            day17::tower_height(&jet, rocks),
+            naive_tower_height(&jet, rocks)
+        );
+    }
+}
@@ -0,0 +1,332 @@
+//! Golden answers: every day is solved on its example files, and on its real input
+//! when `answers.toml` at the root of the workspace has an entry for it.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+fn input_file(day: u8, file: &str) -> PathBuf {
+    root().join(format!("day{:02}", day)).join(file)
+}
+
+/// Compare answers line by line, ignoring trailing whitespace (the day 10 CRT pads its lines).
+fn normalize(answer: &str) -> String {
+    answer
+        .trim_matches('\n')
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn solve(day: u8, path: &Path, part: u8) -> String {
+    let input =
+        fs::read_to_string(path).unwrap_or_else(|err| panic!("{}: {}", path.display(), err));
+    let day = aoc::day(day).unwrap();
+    let answers = (day.solve)(&input, &[part])
+        .unwrap_or_else(|err| panic!("{}", err.render(&path.display().to_string(), &input)));
+    answers[0].answer.to_string()
+}
+
+fn check_example(day: u8, file: &str, part: u8, expected: &str) {
+    let path = input_file(day, file);
+    let answer = solve(day, &path, part);
+    assert_eq!(
+        normalize(&answer),
+        normalize(expected),
+        "day {} part {} diverged on {}",
+        day,
+        part,
+        path.display()
+    );
+}
+
+fn answers() -> Option<&'static toml::Table> {
+    static ANSWERS: OnceLock<Option<toml::Table>> = OnceLock::new();
+    ANSWERS
+        .get_or_init(|| {
+            let text = fs::read_to_string(root().join("answers.toml")).ok()?;
+            Some(text.parse().expect("answers.toml is not valid TOML"))
+        })
+        .as_ref()
+}
+
+fn check_input(day: u8, part: u8) {
+    let expected = answers()
+        .and_then(|answers| answers.get(&format!("day{:02}", day)))
+        .and_then(|answers| answers.get(format!("part{}", part)));
+    let path = input_file(day, "data.txt");
+    let (Some(expected), true) = (expected, path.exists()) else {
+        eprintln!(
+            "day {} part {}: no real input or answer, skipped",
+            day, part
+        );
+        return;
+    };
+    let expected = match expected {
+        toml::Value::String(s) => s.clone(),
+        v => v.to_string(),
+    };
+    let answer = solve(day, &path, part);
+    assert_eq!(
+        normalize(&answer),
+        normalize(&expected),
+        "day {} part {} diverged on {}",
+        day,
+        part,
+        path.display()
+    );
+}
+
+macro_rules! golden {
+    ($(#[$attr:meta])* $name:ident: $file:literal, part $part:literal => $expected:expr) => {
+        $(#[$attr])*
+        #[test]
+        fn $name() {
+            crate::check_example(DAY, $file, $part, $expected);
+        }
+    };
+    ($(#[$attr:meta])* $name:ident: input, part $part:literal) => {
+        $(#[$attr])*
+        #[test]
+        fn $name() {
+            crate::check_input(DAY, $part);
+        }
+    };
+}
+
+mod day01 {
+    const DAY: u8 = 1;
+    golden!(example_part1: "data.test.txt", part 1 => "24000");
+    golden!(example_part2: "data.test.txt", part 2 => "45000");
+    golden!(input_part1: input, part 1);
+    golden!(input_part2: input, part 2);
+}
+
+mod day02 {
+    const DAY: u8 = 2;
+    golden!(example_part1: "data.test.txt", part 1 => "15");
+    golden!(example_part2: "data.test.txt", part 2 => "12");
+    golden!(input_part1: input, part 1);
+    golden!(input_part2: input, part 2);
+}
+
+mod day03 {
+    const DAY: u8 = 3;
+    golden!(example_part1: "data.test.txt", part 1 => "157");
+    golden!(example_part2: "data.test.txt", part 2 => "70");
+    golden!(input_part1: input, part 1);
+    golden!(input_part2: input, part 2);
+}
+
+mod day04 {
+    const DAY: u8 = 4;
+    golden!(example_part1: "data.test.txt", part 1 => "2");
+    golden!(example_part2: "data.test.txt", part 2 => "4");
+    golden!(input_part1: input, part 1);
+    golden!(input_part2: input, part 2);
+}
+
+mod day05 {
+    const DAY: u8 = 5;
+    golden!(example_part1: "data.test.txt", part 1 => "CMZ");
+    golden!(example_part2: "data.test.txt", part 2 => "MCD");
+    golden!(input_part1: input, part 1);
+    golden!(input_part2: input, part 2);
+}
+
+mod day06 {
+    const DAY: u8 = 6;
+    golden!(example1_part1: "data1.test.txt", part 1 => "5");
+    golden!(example1_part2: "data1.test.txt", part 2 => "23");
+    golden!(example2_part1: "data2.test.txt", part 1 => "6");
+    golden!(example2_part2: "data2.test.txt", part 2 => "23");
+    golden!(example3_part1: "data3.test.txt", part 1 => "10");
+    golden!(example3_part2: "data3.test.txt", part 2 => "29");
+    golden!(example4_part1: "data4.test.txt", part 1 => "11");
+    golden!(example4_part2: "data4.test.txt", part 2 => "26");
+    golden!(input_part1: input, part 1);
+    golden!(input_part2: input, part 2);
+}
+
+mod day07 {
+    const DAY: u8 = 7;
+    golden!(example_part1: "data.test.txt", part 1 => "95437");
+    golden!(example_part2: "data.test.txt", part 2 => "24933642");
+    golden!(input_part1: input, part 1);
+    golden!(input_part2: input, part 2);
+}
+
+mod day08 {
+    const DAY: u8 = 8;
+    golden!(example_part1: "data.test.txt", part 1 => "21");
+    golden!(example_part2: "data.test.txt", part 2 => "8");
+    golden!(input_part1: input, part 1);
+    golden!(input_part2: input, part 2);
+}
+
+mod day09 {
+    const DAY: u8 = 9;
+    golden!(example_part1: "data.test.txt", part 1 => "13");
+    golden!(example_part2: "data.test.txt", part 2 => "1");
+    golden!(input_part1: input, part 1);
+    golden!(input_part2: input, part 2);
+}
+
+mod day10 {
+    const DAY: u8 = 10;
+    golden!(example_part1: "data.test.txt", part 1 => "13140");
+    golden!(example_part2: "data.test.txt", part 2 => "
+##  ##  ##  ##  ##  ##  ##  ##  ##  ##
+###   ###   ###   ###   ###   ###   ###
+####    ####    ####    ####    ####
+#####     #####     #####     #####
+######      ######      ######      ####
+#######       #######       #######
+");
+    golden!(input_part1: input, part 1);
+    golden!(input_part2: input, part 2);
+}
+
+mod day11 {
+    const DAY: u8 = 11;
+    golden!(example_part1: "data.test.txt", part 1 => "10605");
+    golden!(example_part2: "data.test.txt", part 2 => "2713310158");
+    golden!(input_part1: input, part 1);
+    golden!(input_part2: input, part 2);
+}
+
+mod day12 {
+    const DAY: u8 = 12;
+    golden!(example_part1: "data.test.txt", part 1 => "31");
+    golden!(example_part2: "data.test.txt", part 2 => "29");
+    golden!(input_part1: input, part 1);
+    golden!(input_part2: input, part 2);
+}
+
+mod day13 {
+    const DAY: u8 = 13;
+    golden!(example_part1: "data.test.txt", part 1 => "13");
+    golden!(example_part2: "data.test.txt", part 2 => "140");
+    golden!(input_part1: input, part 1);
+    golden!(input_part2: input, part 2);
+}
+
+mod day14 {
+    const DAY: u8 = 14;
+    golden!(example_part1: "data.test.txt", part 1 => "24");
+    golden!(example_part2: "data.test.txt", part 2 => "93");
+    golden!(input_part1: input, part 1);
+    golden!(input_part2: input, part 2);
+}
+
+mod day15 {
+    const DAY: u8 = 15;
+    golden!(example_part1: "data.test.txt", part 1 => "26");
+    golden!(
+        #[ignore = "the search area of part 2 is hardcoded for the real input"]
+        example_part2: "data.test.txt", part 2 => "56000011"
+    );
+    golden!(input_part1: input, part 1);
+    golden!(input_part2: input, part 2);
+}
+
+mod day16 {
+    const DAY: u8 = 16;
+    golden!(example_part1: "data.test.txt", part 1 => "1651");
+    golden!(example_part2: "data.test.txt", part 2 => "1707");
+    golden!(input_part1: input, part 1);
+    golden!(
+        #[ignore = "slow, run with --release -- --ignored"]
+        input_part2: input, part 2
+    );
+}
+
+mod day17 {
+    const DAY: u8 = 17;
+    golden!(example_part1: "data.test.txt", part 1 => "3068");
+    golden!(example_part2: "data.test.txt", part 2 => "1514285714288");
+    golden!(input_part1: input, part 1);
+    golden!(input_part2: input, part 2);
+}
+
+mod day18 {
+    const DAY: u8 = 18;
+    golden!(example_part1: "data.test.txt", part 1 => "64");
+    golden!(example_part2: "data.test.txt", part 2 => "58");
+    golden!(input_part1: input, part 1);
+    golden!(
+        #[ignore = "slow, run with --release -- --ignored"]
+        input_part2: input, part 2
+    );
+}
+
+mod day19 {
+    const DAY: u8 = 19;
+    golden!(
+        #[ignore = "slow, run with --release -- --ignored"]
+        example_part1: "data.test.txt", part 1 => "33"
+    );
+    golden!(example_part2: "data.test.txt", part 2 => "3472");
+    golden!(
+        #[ignore = "slow, run with --release -- --ignored"]
+        input_part1: input, part 1
+    );
+    golden!(
+        #[ignore = "slow, run with --release -- --ignored"]
+        input_part2: input, part 2
+    );
+}
+
+mod day20 {
+    const DAY: u8 = 20;
+    golden!(example_part1: "data.test.txt", part 1 => "3");
+    golden!(example_part2: "data.test.txt", part 2 => "1623178306");
+    golden!(input_part1: input, part 1);
+    golden!(input_part2: input, part 2);
+}
+
+mod day21 {
+    const DAY: u8 = 21;
+    golden!(example_part1: "data.test.txt", part 1 => "152");
+    golden!(example_part2: "data.test.txt", part 2 => "301");
+    golden!(input_part1: input, part 1);
+    golden!(input_part2: input, part 2);
+}
+
+mod day22 {
+    const DAY: u8 = 22;
+    golden!(example_part1: "data.test.txt", part 1 => "6032");
+    golden!(
+        #[ignore = "the cube folding of part 2 is hardcoded for the real input"]
+        example_part2: "data.test.txt", part 2 => "5031"
+    );
+    golden!(input_part1: input, part 1);
+    golden!(input_part2: input, part 2);
+}
+
+mod day23 {
+    const DAY: u8 = 23;
+    golden!(example_part1: "data.test.txt", part 1 => "110");
+    golden!(example_part2: "data.test.txt", part 2 => "20");
+    golden!(input_part1: input, part 1);
+    golden!(input_part2: input, part 2);
+}
+
+mod day24 {
+    const DAY: u8 = 24;
+    golden!(example_part1: "data.test.txt", part 1 => "18");
+    golden!(example_part2: "data.test.txt", part 2 => "54");
+    golden!(input_part1: input, part 1);
+    golden!(input_part2: input, part 2);
+}
+
+mod day25 {
+    const DAY: u8 = 25;
+    golden!(example_part1: "data.test.txt", part 1 => "2=-1=0");
+    golden!(input_part1: input, part 1);
+}
@@ -0,0 +1,66 @@
+//! Inputs whose answers, or the numbers on the way to them, do not fit the integer types of
+//! the solvers: an error without the `bigint` feature, the exact answer with it.
+
+use common::{Answer, SolveError};
+
+fn solve(day: u8, part: u8, input: &str) -> Result<Answer, SolveError> {
+    let day = aoc::day(day).unwrap();
+    let mut answers = (day.solve)(input, &[part])?;
+    Ok(answers.remove(0).answer)
+}
+
+#[cfg(not(feature = "bigint"))]
+fn overflow(result: Result<Answer, SolveError>) -> String {
+    match result {
+        Err(SolveError::Overflow(err)) => err.to_string(),
+        other => panic!("expected an overflow, got {:?}", other),
+    }
+}
+
+const CALORIES: &str = "4000000000\n\n4000000000\n\n4000000000\n";
+
+const MONKEYS: &str = "\
+Monkey 0:
+  Starting items: 10000000000
+  Operation: new = old * 10000000000
+  Test: divisible by 2
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 1
+  Operation: new = old + 1
+  Test: divisible by 3
+    If true: throw to monkey 0
+    If false: throw to monkey 0
+";
+
+const JOBS: &str = "root: aaaa * bbbb\naaaa: 10000000000\nbbbb: 10000000000\n";
+
+#[test]
+#[cfg(not(feature = "bigint"))]
+fn overflows_name_the_day_and_operation() {
+    assert_eq!(
+        overflow(solve(1, 2, CALORIES)),
+        "day 1: sum of the top three 4000000000 + 4000000000 + 4000000000 overflows"
+    );
+    assert_eq!(
+        overflow(solve(11, 1, MONKEYS)),
+        "day 11: worry level 10000000000 * 10000000000 overflows"
+    );
+    assert_eq!(
+        overflow(solve(21, 1, JOBS)),
+        "day 21: 10000000000 * 10000000000 overflows"
+    );
+}
+
+#[test]
+#[cfg(feature = "bigint")]
+fn big_integers_give_the_exact_answers() {
+    assert_eq!(solve(1, 2, CALORIES).unwrap(), Answer::UInt(12000000000));
+    assert!(solve(11, 1, MONKEYS).is_ok());
+    assert_eq!(
+        solve(21, 1, JOBS).unwrap(),
+        Answer::Text(String::from("100000000000000000000"))
+    );
+}
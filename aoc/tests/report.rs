@@ -0,0 +1,102 @@
+//! The JSON and CSV records of `aoc run`.
+
+use aoc::alloc::Usage;
+use aoc::report::{Format, Report};
+use common::{Answers, Source};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+
+const CRT: &str = include_str!("../../day10/data.test.txt");
+
+/// The day 10 example answers, the second one spanning several lines.
+fn answers() -> Answers {
+    let mut answers = (aoc::day(10).unwrap().solve)(CRT, &[1, 2]).unwrap();
+    for solved in &mut answers {
+        solved.elapsed = Duration::from_nanos(1500);
+    }
+    answers
+}
+
+fn write(format: Format, usage: &[Usage]) -> String {
+    let mut out = Vec::new();
+    let mut report = Report::new(&mut out, format, false).unwrap();
+    let source = Source::new("day10/data.test.txt");
+    report.day(10, &source, CRT, &answers(), usage).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+/// SHA-256 digest of the day 10 example, as printed by `sha256sum`.
+fn digest() -> String {
+    format!("{:x}", Sha256::digest(CRT.as_bytes()))
+}
+
+#[test]
+fn csv() {
+    let csv = write(Format::Csv, &[]);
+    let (header, rows) = csv.split_once('\n').unwrap();
+    assert_eq!(header, "day,input,part,answer,elapsed_ns,input_sha256");
+    let part1 = format!("10,day10/data.test.txt,1,13140,1500,{}\n", digest());
+    assert!(rows.starts_with(&part1), "{}", rows);
+
+    // The CRT answer is quoted, its line breaks kept inside the field
+    let part2 = &rows[part1.len()..];
+    let crt = answers()[1].answer.to_string();
+    assert!(crt.contains('\n'));
+    let expected = format!("10,day10/data.test.txt,2,\"{}\",1500,{}\n", crt, digest());
+    assert_eq!(part2, expected);
+}
+
+#[test]
+fn json() {
+    let json = write(Format::Json, &[]);
+    let records: Vec<Value> = json
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(records.len(), 2);
+    let fields: Vec<&str> = records[0]
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect();
+    assert_eq!(
+        fields,
+        [
+            "day",
+            "input",
+            "part",
+            "answer",
+            "elapsed_ns",
+            "input_sha256"
+        ]
+    );
+    assert_eq!(records[0]["answer"], 13140);
+    assert_eq!(records[0]["elapsed_ns"], 1500);
+    assert_eq!(records[0]["input_sha256"], digest());
+    assert_eq!(records[1]["answer"], answers()[1].answer.to_string());
+    assert!(records.iter().all(|r| r.get("peak_heap_bytes").is_none()));
+}
+
+#[test]
+fn json_heap_usage() {
+    let usage = Usage {
+        peak_heap_bytes: 4096,
+        allocations: 3,
+        allocated_bytes: 5000,
+    };
+    let json = write(Format::Json, &[usage, Usage::default()]);
+    let records: Vec<Value> = json
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(records[0]["peak_heap_bytes"], 4096);
+    assert_eq!(records[0]["allocations"], 3);
+    assert_eq!(records[0]["allocated_bytes"], 5000);
+    assert_eq!(records[1]["peak_heap_bytes"], 0);
+    assert_eq!(records[1].as_object().unwrap().len(), 9);
+
+    // The text report leaves the usage out
+    assert_eq!(write(Format::Text, &[usage]), write(Format::Text, &[]));
+}
@@ -0,0 +1,71 @@
+use std::fmt;
+
+/// The answer to one part of a puzzle, whatever its type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    UInt(u64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(v) => write!(f, "{}", v),
+            Answer::UInt(v) => write!(f, "{}", v),
+            Answer::Text(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(v: i32) -> Self {
+        Answer::Int(v as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(v: i64) -> Self {
+        Answer::Int(v)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(v: u32) -> Self {
+        Answer::UInt(v as u64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(v: u64) -> Self {
+        Answer::UInt(v)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(v: usize) -> Self {
+        Answer::UInt(v as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(v: String) -> Self {
+        Answer::Text(v)
+    }
+}
+
+/// A number when it fits in 64 bits, its digits otherwise.
+#[cfg(feature = "bigint")]
+impl From<num_bigint::BigUint> for Answer {
+    fn from(v: num_bigint::BigUint) -> Self {
+        u64::try_from(&v).map_or_else(|_| Answer::Text(v.to_string()), Answer::UInt)
+    }
+}
+
+/// A number when it fits in 64 bits, its digits otherwise.
+#[cfg(feature = "bigint")]
+impl From<num_bigint::BigInt> for Answer {
+    fn from(v: num_bigint::BigInt) -> Self {
+        i64::try_from(&v).map_or_else(|_| Answer::Text(v.to_string()), Answer::Int)
+    }
+}
@@ -0,0 +1,59 @@
+//! Cooperative cancellation of the solvers.
+//!
+//! A runner hands the thread solving a part a [`Token`] with [`run_cancellable`]. The long
+//! loops of the slow days call [`checkpoint`], which unwinds out of the solver once the token
+//! is cancelled. Outside of [`run_cancellable`], a checkpoint does nothing.
+
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Shared flag asking a solver to stop.
+#[derive(Debug, Clone, Default)]
+pub struct Token(Arc<AtomicBool>);
+
+impl Token {
+    pub fn new() -> Token {
+        Token::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// The solver stopped at a [`checkpoint`] after its token was cancelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+thread_local! {
+    static CURRENT: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+/// Run `f` on this thread, stopping at the first [`checkpoint`] after `token` is cancelled.
+/// Other panics go through.
+pub fn run_cancellable<R>(token: &Token, f: impl FnOnce() -> R) -> Result<R, Cancelled> {
+    let previous = CURRENT.with(|current| current.replace(Some(token.clone())));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CURRENT.with(|current| *current.borrow_mut() = previous);
+    match result {
+        Ok(value) => Ok(value),
+        Err(payload) if payload.is::<Cancelled>() => Err(Cancelled),
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
+
+/// Give up the running solver if its token was cancelled.
+pub fn checkpoint() {
+    let cancelled =
+        CURRENT.with(|current| current.borrow().as_ref().is_some_and(Token::is_cancelled));
+    if cancelled {
+        // Unlike `panic!`, does not run the panic hook: nothing is printed
+        panic::resume_unwind(Box::new(Cancelled));
+    }
+}
@@ -0,0 +1,23 @@
+use crate::ParseError;
+
+/// An assumption a solver makes about its input, beyond what its parser checks, such as a
+/// single start or a layout its shortcuts rely on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assumption {
+    pub description: &'static str,
+    /// Where the input breaks the assumption, empty when it holds.
+    pub violations: Vec<ParseError>,
+}
+
+impl Assumption {
+    pub fn new(description: &'static str, violations: Vec<ParseError>) -> Assumption {
+        Assumption {
+            description,
+            violations,
+        }
+    }
+
+    pub fn holds(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
@@ -0,0 +1,76 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// The source named by a command line argument, `-` being the standard input.
+    pub fn new(arg: impl Into<PathBuf>) -> Source {
+        let path = arg.into();
+        if path == Path::new("-") {
+            Source::Stdin
+        } else {
+            Source::File(path)
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().lock().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Source::File(path) => fs::read_to_string(path),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Expand command line arguments into the inputs to solve, in order: `-` is the standard
+/// input, a directory stands for the `*.txt` files it holds, sorted by name.
+pub fn sources<I>(args: I) -> io::Result<Vec<Source>>
+where
+    I: IntoIterator,
+    I::Item: Into<PathBuf>,
+{
+    let mut sources = Vec::new();
+    for arg in args {
+        match Source::new(arg) {
+            Source::File(dir) if dir.is_dir() => {
+                let mut files = Vec::new();
+                for entry in fs::read_dir(&dir)? {
+                    let path = entry?.path();
+                    if path.is_file() && path.extension().is_some_and(|e| e == "txt") {
+                        files.push(path);
+                    }
+                }
+                if files.is_empty() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::NotFound,
+                        format!("{}: no *.txt file in the directory", dir.display()),
+                    ));
+                }
+                files.sort();
+                sources.extend(files.into_iter().map(Source::File));
+            }
+            source => sources.push(source),
+        }
+    }
+    Ok(sources)
+}
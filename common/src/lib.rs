@@ -0,0 +1,286 @@
+use std::env;
+use std::fmt;
+use std::hint::black_box;
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+use std::time::{Duration, Instant};
+
+mod answer;
+pub mod cancel;
+mod check;
+mod input;
+pub mod overflow;
+mod parse;
+pub mod trace;
+
+pub use answer::Answer;
+pub use check::Assumption;
+pub use input::{sources, Source};
+pub use overflow::Overflow;
+pub use parse::{lines, Line, ParseError};
+
+/// A day of the calendar: the input is parsed once, then shared by both parts.
+pub trait Solution {
+    const DAY: u8;
+    /// Number of parts of the puzzle, day 25 only has one.
+    const PARTS: u8 = 2;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// Check the assumptions of the solver on `input`, without solving it.
+    fn check(_input: &str) -> Vec<Assumption> {
+        Vec::new()
+    }
+}
+
+/// The answer to one part of a day, and the time it took to solve it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// The answers of a day, in the order of the requested parts.
+pub type Answers = Vec<Solved>;
+
+/// Why a day could not be solved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    Overflow(Overflow),
+}
+
+impl SolveError {
+    /// The error for a human, parse errors quoting the line of `input` they are on.
+    pub fn render(&self, path: &str, input: &str) -> String {
+        match self {
+            SolveError::Parse(err) => err.render(path, input),
+            SolveError::Overflow(err) => format!("{}: {}", path, err),
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolveError::Parse(err) => err.fmt(f),
+            SolveError::Overflow(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from(err: ParseError) -> Self {
+        SolveError::Parse(err)
+    }
+}
+
+impl From<Overflow> for SolveError {
+    fn from(err: Overflow) -> Self {
+        SolveError::Overflow(err)
+    }
+}
+
+/// Give up a part on an input that parsed but that it cannot solve. [`solve`] reports it like a
+/// parse error, pointing at the line at fault.
+pub fn reject(err: ParseError) -> ! {
+    // Unlike `panic!`, does not run the panic hook: the error is reported by the caller
+    panic::resume_unwind(Box::new(err))
+}
+
+/// Run `f`, stopping at the first operation that overflows or input rejected by a part. Other
+/// panics go through.
+fn catch<R>(f: impl FnOnce() -> R) -> Result<R, SolveError> {
+    let payload = match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => return Ok(value),
+        Err(payload) => payload,
+    };
+    let payload = match payload.downcast::<Overflow>() {
+        Ok(overflow) => return Err(SolveError::Overflow(*overflow)),
+        Err(payload) => payload,
+    };
+    match payload.downcast::<ParseError>() {
+        Ok(err) => Err(SolveError::Parse(*err)),
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
+
+/// Parse `input` and solve the requested parts of `S`.
+pub fn solve<S: Solution>(input: &str, parts: &[u8]) -> Result<Answers, SolveError> {
+    let _day = span!("day", day = S::DAY);
+    let parsed = {
+        let _parse = span!("parse");
+        S::parse(input)?
+    };
+    let answers = catch(|| {
+        parts
+            .iter()
+            .map(|&part| {
+                let _part = span!("part", part);
+                let start = Instant::now();
+                let answer = match part {
+                    1 => S::part1(&parsed),
+                    2 => S::part2(&parsed),
+                    _ => panic!("day {} has no part {}", S::DAY, part),
+                };
+                let elapsed = start.elapsed();
+                debug!(%answer);
+                Solved {
+                    part,
+                    answer,
+                    elapsed,
+                }
+            })
+            .collect()
+    })?;
+    Ok(answers)
+}
+
+/// A step of a solution that can be timed on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stage {
+    Parse,
+    Part(u8),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+/// Callback handed each [`Stage`] of a solution along with a closure running it, as many times as it likes.
+pub type Probe<'a> = dyn FnMut(Stage, &mut dyn FnMut()) + 'a;
+
+/// Hand the parse step and every part of `S` to `probe`, the parts sharing a single parsed input.
+pub fn measure<S: Solution>(input: &str, probe: &mut Probe) -> Result<(), SolveError> {
+    let parsed = S::parse(input)?;
+    probe(Stage::Parse, &mut || {
+        black_box(S::parse(input).ok());
+    });
+    catch(|| {
+        for part in 1..=S::PARTS {
+            probe(Stage::Part(part), &mut || match part {
+                1 => drop(black_box(S::part1(&parsed))),
+                _ => drop(black_box(S::part2(&parsed))),
+            });
+        }
+    })?;
+    Ok(())
+}
+
+/// Type-erased handle on a [`Solution`], so that days can be listed and dispatched at runtime.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    pub parts: u8,
+    pub solve: fn(&str, &[u8]) -> Result<Answers, SolveError>,
+    pub measure: fn(&str, &mut Probe) -> Result<(), SolveError>,
+    pub check: fn(&str) -> Vec<Assumption>,
+}
+
+impl Day {
+    pub fn of<S: Solution>() -> Day {
+        Day {
+            number: S::DAY,
+            parts: S::PARTS,
+            solve: solve::<S>,
+            measure: measure::<S>,
+            check: S::check,
+        }
+    }
+
+    /// Default location of the puzzle input, relative to the workspace root.
+    pub fn input_path(&self) -> String {
+        format!("day{:02}/data.txt", self.number)
+    }
+}
+
+/// The puzzle inputs named on the command line, see [`sources`], or `data.txt` when none is given.
+pub fn input_sources() -> io::Result<Vec<Source>> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.is_empty() {
+        return Ok(vec![Source::new("data.txt")]);
+    }
+    sources(args)
+}
+
+/// Write an answer, moving multi-line answers (such as the day 10 CRT) to their own lines.
+pub fn write_answer(out: &mut impl Write, part: u8, answer: &Answer) -> io::Result<()> {
+    if answer.to_string().contains('\n') {
+        writeln!(out, "part {}:\n{}", part, answer)
+    } else {
+        writeln!(out, "part {}: {}", part, answer)
+    }
+}
+
+/// Print an answer on the standard output, see [`write_answer`].
+pub fn print_answer(part: u8, answer: &Answer) {
+    write_answer(&mut io::stdout().lock(), part, answer).expect("failed printing to stdout");
+}
+
+/// Entry point shared by the day binaries. Several inputs are solved in turn, their answers
+/// grouped under the name of the input; the exit status is 1 if any of them failed.
+pub fn main<S: Solution>() {
+    let sources = input_sources().unwrap_or_else(|err| fail(err));
+    let parts = (1..=S::PARTS).collect::<Vec<u8>>();
+    let mut failed = false;
+    for source in &sources {
+        if sources.len() > 1 {
+            println!("{}:", source);
+        }
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("error: {}: {}", source, err);
+                failed = true;
+                continue;
+            }
+        };
+        match solve::<S>(&input, &parts) {
+            Ok(answers) => {
+                for solved in answers {
+                    print_answer(solved.part, &solved.answer);
+                }
+            }
+            Err(err) => {
+                eprintln!("error: {}", err.render(&source.to_string(), &input));
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+/// The inputs named on the command line, each parsed as it is reached. The first one that
+/// cannot be read or parsed ends the program with a rendered error, as in the day examples.
+pub fn parsed_inputs<S: Solution>() -> impl Iterator<Item = (Source, S::Input)> {
+    let sources = input_sources().unwrap_or_else(|err| fail(err));
+    sources.into_iter().map(|source| {
+        let input = source
+            .read()
+            .unwrap_or_else(|err| fail(format!("{}: {}", source, err)));
+        let parsed =
+            S::parse(&input).unwrap_or_else(|err| fail(err.render(&source.to_string(), &input)));
+        (source, parsed)
+    })
+}
+
+/// Print an error on the standard error and exit with status 1.
+pub fn fail(message: impl fmt::Display) -> ! {
+    eprintln!("error: {}", message);
+    process::exit(1);
+}
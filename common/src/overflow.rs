@@ -0,0 +1,36 @@
+//! Arithmetic overflow in the solvers.
+//!
+//! The parts return their answer as is, so an operation that does not fit its integer type
+//! gives up the solver with [`checked`]. [`solve`](crate::solve) turns it into an [`Overflow`]
+//! error naming the day and the operation.
+
+use std::fmt;
+use std::panic;
+
+/// An operation of a solver whose result does not fit its integer type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    pub day: u8,
+    /// The operation and its operands, such as `worry level 79 * 19`.
+    pub operation: String,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "day {}: {} overflows", self.day, self.operation)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// The result of a checked operation, giving up the solver when it is `None`.
+pub fn checked<T>(value: Option<T>, day: u8, operation: impl FnOnce() -> String) -> T {
+    value.unwrap_or_else(|| {
+        let overflow = Overflow {
+            day,
+            operation: operation(),
+        };
+        // Unlike `panic!`, does not run the panic hook: the error is reported by the caller
+        panic::resume_unwind(Box::new(overflow))
+    })
+}
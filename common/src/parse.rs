@@ -0,0 +1,135 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Error returned by a day's parser when the puzzle input does not have the expected shape.
+///
+/// Lines and columns are 1-based, columns count characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line_no: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    /// Error at the end of the input, when more lines were expected.
+    pub fn end_of_input(day: u8, input: &str, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            day,
+            line_no: input.lines().count() + 1,
+            column: 1,
+            expected: expected.into(),
+            found: String::from("end of input"),
+        }
+    }
+
+    /// Render the error like a compiler diagnostic, quoting the offending line of `input`.
+    pub fn render(&self, path: &str, input: &str) -> String {
+        let mut out = format!(
+            "day {}: expected {}, found {}\n",
+            self.day, self.expected, self.found
+        );
+        let gutter = self.line_no.to_string().len();
+        out += &format!(
+            "{:gutter$}--> {}:{}:{}\n",
+            "", path, self.line_no, self.column
+        );
+        if let Some(line) = input.lines().nth(self.line_no.saturating_sub(1)) {
+            out += &format!("{:gutter$} |\n", "");
+            out += &format!("{} | {}\n", self.line_no, line);
+            out += &format!("{:gutter$} | {:>col$}", "", "^", col = self.column);
+        }
+        out
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: expected {}, found {}",
+            self.day, self.line_no, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// One line of a puzzle input, which knows where it comes from to build [`ParseError`]s.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub day: u8,
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Column of `rest`, which must be a slice of this line (typically what a parser left over).
+    pub fn column_of(&self, rest: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (rest.as_ptr() as usize)
+            .saturating_sub(start)
+            .min(self.text.len());
+        self.text
+            .get(..offset)
+            .map_or(1, |prefix| prefix.chars().count() + 1)
+    }
+
+    pub fn error(
+        &self,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> ParseError {
+        ParseError {
+            day: self.day,
+            line_no: self.number,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// Error pointing at the start of `rest`, reporting the token found there.
+    pub fn error_at(&self, rest: &str, expected: impl Into<String>) -> ParseError {
+        let token = rest.split_whitespace().next().unwrap_or("");
+        let found = if token.is_empty() {
+            String::from("end of line")
+        } else {
+            format!("`{}`", token)
+        };
+        self.error(self.column_of(rest), expected, found)
+    }
+
+    /// Error for a line that does not match the expected shape at all.
+    pub fn mismatch(&self, expected: impl Into<String>) -> ParseError {
+        if self.text.is_empty() {
+            self.error(1, expected, "an empty line")
+        } else {
+            self.error_at(self.text, expected)
+        }
+    }
+
+    /// Parse `token`, a slice of this line, reporting its position on failure.
+    pub fn parse<T: FromStr>(&self, token: &str, expected: &str) -> Result<T, ParseError> {
+        token.parse::<T>().map_err(|_| {
+            let found = if token.is_empty() {
+                String::from("nothing")
+            } else {
+                format!("`{}`", token)
+            };
+            self.error(self.column_of(token), expected, found)
+        })
+    }
+}
+
+/// Iterate over the lines of `input`, numbered from 1.
+pub fn lines(day: u8, input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(move |(index, text)| Line {
+        day,
+        number: index + 1,
+        text,
+    })
+}
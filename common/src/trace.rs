@@ -0,0 +1,70 @@
+//! Tracing of the solvers, compiled in with the `trace` feature.
+//!
+//! The days use [`span!`](crate::span), [`debug!`](crate::debug) and [`trace!`](crate::trace)
+//! with the syntax of the `tracing` macros. Without the feature they sit in an `if false`
+//! block: still type checked, but optimized away along with their arguments.
+
+#[doc(hidden)]
+pub use tracing;
+
+/// What [`span!`](crate::span) returns when tracing is compiled out.
+#[must_use]
+pub struct Disabled;
+
+/// Enter a debug level span until the returned guard is dropped.
+#[cfg(feature = "trace")]
+#[macro_export]
+macro_rules! span {
+    ($($arg:tt)*) => {
+        $crate::trace::tracing::debug_span!($($arg)*).entered()
+    };
+}
+
+#[cfg(not(feature = "trace"))]
+#[macro_export]
+macro_rules! span {
+    ($($arg:tt)*) => {{
+        if false {
+            let _ = $crate::trace::tracing::debug_span!($($arg)*);
+        }
+        $crate::trace::Disabled
+    }};
+}
+
+/// Debug level event.
+#[cfg(feature = "trace")]
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        $crate::trace::tracing::debug!($($arg)*)
+    };
+}
+
+#[cfg(not(feature = "trace"))]
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if false {
+            $crate::trace::tracing::debug!($($arg)*)
+        }
+    };
+}
+
+/// Trace level event, for the details of every step.
+#[cfg(feature = "trace")]
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        $crate::trace::tracing::trace!($($arg)*)
+    };
+}
+
+#[cfg(not(feature = "trace"))]
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if false {
+            $crate::trace::tracing::trace!($($arg)*)
+        }
+    };
+}
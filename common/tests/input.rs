@@ -0,0 +1,58 @@
+use common::{sources, Source};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// A fresh directory for the test `name`.
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("sources-{}-{}", std::process::id(), name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn stdin_and_files() {
+    let sources = sources(["-", "day01/data.txt", "missing.txt"]).unwrap();
+    assert_eq!(
+        sources,
+        [
+            Source::Stdin,
+            Source::File("day01/data.txt".into()),
+            Source::File("missing.txt".into()),
+        ]
+    );
+    assert_eq!(sources[0].to_string(), "<stdin>");
+}
+
+#[test]
+fn directory_of_inputs() {
+    let dir = temp_dir("inputs");
+    for file in ["b.txt", "a.txt", "notes.md", "c.txt.bak"] {
+        fs::write(dir.join(file), "1\n").unwrap();
+    }
+    // A directory is not an input, even when named like one
+    fs::create_dir(dir.join("nested.txt")).unwrap();
+    let expanded = sources([PathBuf::from("-"), dir.clone()]).unwrap();
+    assert_eq!(
+        expanded,
+        [
+            Source::Stdin,
+            Source::File(dir.join("a.txt")),
+            Source::File(dir.join("b.txt")),
+        ]
+    );
+    assert_eq!(expanded[1].read().unwrap(), "1\n");
+}
+
+#[test]
+fn empty_directory() {
+    let dir = temp_dir("empty");
+    fs::write(dir.join("readme.md"), "").unwrap();
+    let err = sources([dir.clone()]).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    assert_eq!(
+        err.to_string(),
+        format!("{}: no *.txt file in the directory", dir.display())
+    );
+}
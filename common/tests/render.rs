@@ -0,0 +1,31 @@
+use common::{lines, ParseError};
+
+#[test]
+fn render_multi_digit_line() {
+    let input: String = (1..=11).map(|i| format!("{}\n", i)).collect::<String>() + "é∂x\n";
+    let line = lines(7, &input).nth(11).unwrap();
+    let err = line.error_at(&line.text[5..], "a digit");
+    assert_eq!(err.column, 3);
+    assert_eq!(
+        err.render("in.txt", &input),
+        "day 7: expected a digit, found `x`\n  --> in.txt:12:3\n   |\n12 | é∂x\n   |   ^"
+    );
+}
+
+#[test]
+fn render_single_digit_line() {
+    let err = lines(2, "A Q\n").next().unwrap().mismatch("a round");
+    assert_eq!(
+        err.render("-", "A Q\n"),
+        "day 2: expected a round, found `A`\n --> -:1:1\n  |\n1 | A Q\n  | ^"
+    );
+}
+
+#[test]
+fn render_end_of_input() {
+    let err = ParseError::end_of_input(15, "a\nb\n", "a sensor");
+    assert_eq!(
+        err.render("data.txt", "a\nb\n"),
+        "day 15: expected a sensor, found end of input\n --> data.txt:3:1\n"
+    );
+}
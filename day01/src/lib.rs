@@ -0,0 +1,185 @@
+// Calorie counts are only `Copy` without the `bigint` feature
+#![cfg_attr(not(feature = "bigint"), allow(clippy::clone_on_copy, clippy::op_ref))]
+
+use common::overflow::checked;
+use common::{lines, Answer, Line, ParseError, Solution};
+use num_traits::{CheckedAdd, ToPrimitive};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap};
+use std::io::{self, BufRead};
+
+pub struct Day01;
+
+#[cfg(not(feature = "bigint"))]
+pub type Calories = u32;
+#[cfg(feature = "bigint")]
+pub type Calories = num_bigint::BigUint;
+
+/// An elf and the calories it carries. Elves are numbered from 1, in input order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    pub id: usize,
+    pub calories: Calories,
+}
+
+/// Total calories carried by each elf, in input order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Inventory {
+    elves: Vec<Calories>,
+}
+
+impl Inventory {
+    /// Read an inventory line by line, without holding the whole input in memory. Parse errors
+    /// come as [`io::ErrorKind::InvalidData`] errors wrapping a [`ParseError`].
+    pub fn from_reader(mut reader: impl BufRead) -> io::Result<Inventory> {
+        let mut inventory = Inventory::default();
+        let mut current = None;
+        let mut text = String::new();
+        for number in 1.. {
+            text.clear();
+            if reader.read_line(&mut text)? == 0 {
+                break;
+            }
+            let line = Line {
+                day: Day01::DAY,
+                number,
+                text: text.trim_end_matches(['\n', '\r']),
+            };
+            inventory
+                .push_line(line, &mut current)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        }
+        inventory.elves.extend(current);
+        Ok(inventory)
+    }
+
+    /// Add the calories on `line` to the elf being read, `current`, or close it on a blank line.
+    fn push_line(&mut self, line: Line, current: &mut Option<Calories>) -> Result<(), ParseError> {
+        match line.text {
+            "" => self.elves.extend(current.take()),
+            text => {
+                let calories = line.parse::<Calories>(text, "a calorie count")?;
+                let total = current.get_or_insert(Calories::from(0u32));
+                *total = CheckedAdd::checked_add(&*total, &calories).ok_or_else(|| {
+                    // Only without the `bigint` feature, the total being a `u32`
+                    let operation = format!(
+                        "calories of elf {} {} + {} overflowing",
+                        self.elves.len() + 1,
+                        total,
+                        calories
+                    );
+                    line.error(
+                        1,
+                        "a calorie count the total of the elf can take",
+                        operation,
+                    )
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.elves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elves.is_empty()
+    }
+
+    pub fn elves(&self) -> impl Iterator<Item = Elf> + '_ {
+        self.elves.iter().enumerate().map(|(i, calories)| Elf {
+            id: i + 1,
+            calories: calories.clone(),
+        })
+    }
+
+    /// The `n` elves carrying the most calories, most first. Ties go to the first elf.
+    pub fn top_n(&self, n: usize) -> Vec<Elf> {
+        // Min-heap of the best elves so far, the worst of them on top
+        let mut best = BinaryHeap::with_capacity(n.min(self.len()) + 1);
+        for (i, calories) in self.elves.iter().enumerate() {
+            best.push(Reverse((calories, Reverse(i))));
+            if best.len() > n {
+                best.pop();
+            }
+        }
+        best.into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((calories, Reverse(i)))| Elf {
+                id: i + 1,
+                calories: calories.clone(),
+            })
+            .collect()
+    }
+
+    /// Mean calories per elf, `None` without elves.
+    pub fn mean(&self) -> Option<f64> {
+        let sum: f64 = self.elves.iter().filter_map(ToPrimitive::to_f64).sum();
+        (!self.is_empty()).then(|| sum / self.len() as f64)
+    }
+
+    /// Median calories per elf, the mean of the two middle elves for an even count.
+    pub fn median(&self) -> Option<f64> {
+        let mut sorted: Vec<&Calories> = self.elves.iter().collect();
+        sorted.sort_unstable();
+        let middle = |i: usize| sorted[i].to_f64().unwrap_or(f64::INFINITY);
+        match sorted.len() {
+            0 => None,
+            len if len % 2 == 1 => Some(middle(len / 2)),
+            len => Some((middle(len / 2 - 1) + middle(len / 2)) / 2.0),
+        }
+    }
+
+    /// Number of elves in each bucket of `width` calories, keyed by the lowest count of the
+    /// bucket. Empty buckets are left out.
+    ///
+    /// # Panics
+    ///
+    /// When `width` is 0.
+    pub fn histogram(&self, width: u32) -> BTreeMap<Calories, usize> {
+        assert!(width > 0, "histogram buckets must not be empty");
+        let width = Calories::from(width);
+        let mut buckets = BTreeMap::new();
+        for calories in &self.elves {
+            *buckets.entry(calories / &width * &width).or_default() += 1;
+        }
+        buckets
+    }
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Inventory;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut inventory = Inventory::default();
+        let mut current = None;
+        for line in lines(Self::DAY, input) {
+            inventory.push_line(line, &mut current)?;
+        }
+        inventory.elves.extend(current);
+        Ok(inventory)
+    }
+
+    fn part1(inventory: &Self::Input) -> Answer {
+        let top = inventory.top_n(1).pop();
+        top.map_or_else(Calories::default, |elf| elf.calories)
+            .into()
+    }
+
+    fn part2(inventory: &Self::Input) -> Answer {
+        let top = inventory.top_n(3);
+        let mut sum = Calories::default();
+        for elf in &top {
+            let total = CheckedAdd::checked_add(&sum, &elf.calories);
+            sum = checked(total, Self::DAY, || {
+                let top: Vec<String> = top.iter().map(|elf| elf.calories.to_string()).collect();
+                format!("sum of the top three {}", top.join(" + "))
+            });
+        }
+        sum.into()
+    }
+}
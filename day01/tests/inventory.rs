@@ -0,0 +1,96 @@
+use common::Solution;
+use day01::{Calories, Day01, Elf, Inventory};
+use std::collections::BTreeMap;
+use std::io::Cursor;
+
+const EXAMPLE: &str = include_str!("../data.test.txt");
+
+fn elf(id: usize, calories: u32) -> Elf {
+    Elf {
+        id,
+        calories: Calories::from(calories),
+    }
+}
+
+#[test]
+fn top_n() {
+    let inventory = Day01::parse(EXAMPLE).unwrap();
+    assert_eq!(inventory.len(), 5);
+    assert_eq!(
+        inventory.top_n(3),
+        vec![elf(4, 24000), elf(3, 11000), elf(5, 10000)]
+    );
+    assert_eq!(inventory.top_n(0), vec![]);
+    assert_eq!(inventory.top_n(10).len(), 5);
+    assert_eq!(inventory.top_n(usize::MAX), inventory.top_n(5));
+}
+
+#[test]
+fn top_n_ties() {
+    let inventory = Day01::parse("5\n\n7\n\n5\n\n7\n").unwrap();
+    assert_eq!(inventory.top_n(3), vec![elf(2, 7), elf(4, 7), elf(1, 5)]);
+}
+
+#[test]
+fn last_elf() {
+    let inventory = Day01::parse("1\n2\n\n3").unwrap();
+    assert_eq!(
+        inventory.elves().collect::<Vec<_>>(),
+        vec![elf(1, 3), elf(2, 3)]
+    );
+    assert_eq!(Day01::parse("1\n2\n\n3\n").unwrap(), inventory);
+    assert_eq!(Day01::parse("1\n2\n\n3\n\n").unwrap(), inventory);
+}
+
+#[test]
+fn from_reader() {
+    let inventory = Inventory::from_reader(Cursor::new(EXAMPLE)).unwrap();
+    assert_eq!(inventory, Day01::parse(EXAMPLE).unwrap());
+    let crlf = EXAMPLE.replace('\n', "\r\n");
+    assert_eq!(Inventory::from_reader(crlf.as_bytes()).unwrap(), inventory);
+
+    let err = Inventory::from_reader("1000\n\nabc\n".as_bytes()).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn statistics() {
+    let inventory = Day01::parse(EXAMPLE).unwrap();
+    assert_eq!(inventory.mean(), Some(11000.0));
+    assert_eq!(inventory.median(), Some(10000.0));
+    assert_eq!(Day01::parse("1\n\n4").unwrap().median(), Some(2.5));
+    assert_eq!(Inventory::default().mean(), None);
+    assert_eq!(Inventory::default().median(), None);
+}
+
+#[test]
+fn histogram() {
+    let inventory = Day01::parse(EXAMPLE).unwrap();
+    let expected: BTreeMap<Calories, usize> = [(0u32, 2), (10000, 2), (20000, 1)]
+        .into_iter()
+        .map(|(bucket, count)| (Calories::from(bucket), count))
+        .collect();
+    assert_eq!(inventory.histogram(10000), expected);
+}
+
+#[test]
+fn elf_total_overflow() {
+    let input = "1000\n\n4000000000\n300000000\n";
+    #[cfg(not(feature = "bigint"))]
+    {
+        let err = Day01::parse(input).unwrap_err();
+        assert_eq!((err.line_no, err.column), (4, 1));
+        assert_eq!(
+            err.found,
+            "calories of elf 2 4000000000 + 300000000 overflowing"
+        );
+    }
+    #[cfg(feature = "bigint")]
+    assert_eq!(
+        Day01::parse(input).unwrap().top_n(1),
+        vec![Elf {
+            id: 2,
+            calories: Calories::from(4300000000u64)
+        }]
+    );
+}
@@ -0,0 +1,31 @@
+//! Round by round breakdown of a strategy guide under both readings, with the best score
+//! achievable and the score of every other way to read `X`, `Y` and `Z` as shapes.
+//!
+//! cargo run -p day02 --example report -- data.txt
+
+use day02::{Day02, Engine, Strategy};
+
+fn main() {
+    for (source, guide) in common::parsed_inputs::<Day02>() {
+        let engine = Engine::classic();
+        for (title, strategy) in [
+            ("as shapes", Strategy::shapes()),
+            ("as outcomes", Strategy::outcomes()),
+        ] {
+            let report = engine.report(&strategy, &guide).unwrap();
+            println!("{}, {}:\n{}", source, title, report);
+        }
+        println!(
+            "best achievable: {} points",
+            engine.best_score(&guide).unwrap()
+        );
+        println!("letters as shapes:");
+        for (mapping, score) in engine.mappings(&['X', 'Y', 'Z'], &guide) {
+            let shapes: Vec<String> = mapping
+                .iter()
+                .map(|(letter, shape)| format!("{} {:<8}", letter, shape))
+                .collect();
+            println!("  {}  {:>6}", shapes.join("  "), score);
+        }
+    }
+}
@@ -0,0 +1,399 @@
+use common::{lines, Answer, ParseError, Solution};
+use std::collections::BTreeMap;
+use std::fmt;
+
+pub struct Day02;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+    Lizard,
+    Spock,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Shape::Rock => "rock",
+            Shape::Paper => "paper",
+            Shape::Scissors => "scissors",
+            Shape::Lizard => "lizard",
+            Shape::Spock => "Spock",
+        };
+        f.pad(name)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Outcome::Loss => "loss",
+            Outcome::Draw => "draw",
+            Outcome::Win => "win",
+        };
+        f.pad(name)
+    }
+}
+
+/// The shapes played in a round, from our side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: Shape,
+    pub ours: Shape,
+}
+
+/// A scoring rule set: the shapes in play and their points, which shape beats which, and the
+/// points of each outcome. Two shapes that do not beat one another draw.
+#[derive(Debug, Clone)]
+pub struct Rules {
+    shapes: Vec<(Shape, u32)>,
+    beats: Vec<(Shape, Shape)>,
+    /// Points of a loss, a draw and a win
+    outcomes: [u32; 3],
+}
+
+impl Rules {
+    /// Rules with `shapes` and their points, where the first shape of each pair of `beats` beats
+    /// the second. Outcomes are worth 0, 3 and 6 points, as in the puzzle.
+    pub fn new(
+        shapes: impl IntoIterator<Item = (Shape, u32)>,
+        beats: impl IntoIterator<Item = (Shape, Shape)>,
+    ) -> Rules {
+        Rules {
+            shapes: shapes.into_iter().collect(),
+            beats: beats.into_iter().collect(),
+            outcomes: [0, 3, 6],
+        }
+    }
+
+    pub fn with_outcome_points(mut self, loss: u32, draw: u32, win: u32) -> Rules {
+        self.outcomes = [loss, draw, win];
+        self
+    }
+
+    /// Rock, paper, scissors, as scored by the elves.
+    pub fn classic() -> Rules {
+        use Shape::*;
+        Rules::new(
+            [(Rock, 1), (Paper, 2), (Scissors, 3)],
+            [(Rock, Scissors), (Scissors, Paper), (Paper, Rock)],
+        )
+    }
+
+    /// Rock, paper, scissors, lizard, Spock, the two new shapes worth 4 and 5 points.
+    pub fn lizard_spock() -> Rules {
+        use Shape::*;
+        Rules::new(
+            [
+                (Rock, 1),
+                (Paper, 2),
+                (Scissors, 3),
+                (Lizard, 4),
+                (Spock, 5),
+            ],
+            [
+                (Scissors, Paper),
+                (Paper, Rock),
+                (Rock, Lizard),
+                (Lizard, Spock),
+                (Spock, Scissors),
+                (Scissors, Lizard),
+                (Lizard, Paper),
+                (Paper, Spock),
+                (Spock, Rock),
+                (Rock, Scissors),
+            ],
+        )
+    }
+
+    /// The shapes in play, in the order they were given.
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> + '_ {
+        self.shapes.iter().map(|&(shape, _)| shape)
+    }
+
+    /// Points for playing `shape`, `None` when it is not in play.
+    pub fn shape_points(&self, shape: Shape) -> Option<u32> {
+        self.shapes
+            .iter()
+            .find(|&&(s, _)| s == shape)
+            .map(|&(_, points)| points)
+    }
+
+    pub fn outcome_points(&self, outcome: Outcome) -> u32 {
+        self.outcomes[outcome as usize]
+    }
+
+    pub fn outcome(&self, round: Round) -> Outcome {
+        if self.beats.contains(&(round.ours, round.opponent)) {
+            Outcome::Win
+        } else if self.beats.contains(&(round.opponent, round.ours)) {
+            Outcome::Loss
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// Our points for `round`, `None` when we play a shape that is not in play.
+    pub fn score(&self, round: Round) -> Option<u32> {
+        Some(self.shape_points(round.ours)? + self.outcome_points(self.outcome(round)))
+    }
+
+    /// The first shape in play that gets `outcome` against `opponent`.
+    pub fn respond(&self, opponent: Shape, outcome: Outcome) -> Option<Shape> {
+        self.shapes()
+            .find(|&ours| self.outcome(Round { opponent, ours }) == outcome)
+    }
+}
+
+/// How to read our column of the strategy guide.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Strategy {
+    /// Each letter is the shape to play.
+    Shapes(BTreeMap<char, Shape>),
+    /// Each letter is the outcome to aim for.
+    Outcomes(BTreeMap<char, Outcome>),
+}
+
+impl Strategy {
+    /// `X`, `Y` and `Z` are rock, paper and scissors, as first assumed in the puzzle.
+    pub fn shapes() -> Strategy {
+        Strategy::Shapes(BTreeMap::from([
+            ('X', Shape::Rock),
+            ('Y', Shape::Paper),
+            ('Z', Shape::Scissors),
+        ]))
+    }
+
+    /// `X`, `Y` and `Z` mean to lose, draw and win, as the elf later explains.
+    pub fn outcomes() -> Strategy {
+        Strategy::Outcomes(BTreeMap::from([
+            ('X', Outcome::Loss),
+            ('Y', Outcome::Draw),
+            ('Z', Outcome::Win),
+        ]))
+    }
+
+    /// Our shape against `opponent` for `letter`, `None` when the letter means nothing or the
+    /// outcome cannot be had.
+    pub fn play(&self, rules: &Rules, opponent: Shape, letter: char) -> Option<Shape> {
+        match self {
+            Strategy::Shapes(shapes) => shapes.get(&letter).copied(),
+            Strategy::Outcomes(outcomes) => rules.respond(opponent, *outcomes.get(&letter)?),
+        }
+    }
+}
+
+/// Plays a strategy guide: a rule set and the letters of the opponent's shapes.
+#[derive(Debug, Clone)]
+pub struct Engine {
+    pub rules: Rules,
+    pub opponent: BTreeMap<char, Shape>,
+}
+
+impl Engine {
+    /// The classic rules, with `A`, `B` and `C` for rock, paper and scissors.
+    pub fn classic() -> Engine {
+        Engine {
+            rules: Rules::classic(),
+            opponent: BTreeMap::from([
+                ('A', Shape::Rock),
+                ('B', Shape::Paper),
+                ('C', Shape::Scissors),
+            ]),
+        }
+    }
+
+    /// The round played for a line of the guide, `None` when one of its letters means nothing.
+    pub fn round(&self, strategy: &Strategy, (theirs, ours): (char, char)) -> Option<Round> {
+        let opponent = *self.opponent.get(&theirs)?;
+        let ours = strategy.play(&self.rules, opponent, ours)?;
+        Some(Round { opponent, ours })
+    }
+
+    /// Our total score over `guide`, `None` when it cannot be played.
+    pub fn score(&self, strategy: &Strategy, guide: &[(char, char)]) -> Option<u32> {
+        guide
+            .iter()
+            .map(|&letters| self.rules.score(self.round(strategy, letters)?))
+            .sum()
+    }
+
+    /// Every round of `guide` played with `strategy`, `None` when it cannot be played.
+    pub fn report(&self, strategy: &Strategy, guide: &[(char, char)]) -> Option<Report> {
+        let mut report = Report {
+            plays: Vec::with_capacity(guide.len()),
+            wins: 0,
+            draws: 0,
+            losses: 0,
+            score: 0,
+        };
+        for &letters in guide {
+            let round = self.round(strategy, letters)?;
+            let outcome = self.rules.outcome(round);
+            let points = self.rules.score(round)?;
+            match outcome {
+                Outcome::Win => report.wins += 1,
+                Outcome::Draw => report.draws += 1,
+                Outcome::Loss => report.losses += 1,
+            }
+            report.score += points;
+            report.plays.push(Play {
+                round,
+                outcome,
+                points,
+            });
+        }
+        Some(report)
+    }
+
+    /// The highest score over `guide`, playing the best shape in every round whatever our
+    /// letter. `None` when an opponent letter means nothing or no shape is in play.
+    pub fn best_score(&self, guide: &[(char, char)]) -> Option<u32> {
+        guide
+            .iter()
+            .map(|&(theirs, _)| {
+                let opponent = *self.opponent.get(&theirs)?;
+                self.rules
+                    .shapes()
+                    .filter_map(|ours| self.rules.score(Round { opponent, ours }))
+                    .max()
+            })
+            .sum()
+    }
+
+    /// The score over `guide` of every way to read `letters` as distinct shapes in play, best
+    /// first. With the classic rules, the 6 permutations of rock, paper and scissors over `XYZ`.
+    pub fn mappings(
+        &self,
+        letters: &[char],
+        guide: &[(char, char)],
+    ) -> Vec<(BTreeMap<char, Shape>, u32)> {
+        let shapes: Vec<Shape> = self.rules.shapes().collect();
+        let mut mappings = Vec::new();
+        let mut mapping = BTreeMap::new();
+        assign(letters, &shapes, &mut mapping, &mut |mapping| {
+            let strategy = Strategy::Shapes(mapping.clone());
+            if let Some(score) = self.score(&strategy, guide) {
+                mappings.push((mapping.clone(), score));
+            }
+        });
+        // Stable: equal scores stay in the order of the shapes in play
+        mappings.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+        mappings
+    }
+}
+
+/// Call `f` with every assignment of the `letters` to distinct `shapes`, added to `mapping`.
+fn assign(
+    letters: &[char],
+    shapes: &[Shape],
+    mapping: &mut BTreeMap<char, Shape>,
+    f: &mut impl FnMut(&BTreeMap<char, Shape>),
+) {
+    let Some((&letter, rest)) = letters.split_first() else {
+        return f(mapping);
+    };
+    for &shape in shapes {
+        if !mapping.values().any(|&s| s == shape) {
+            mapping.insert(letter, shape);
+            assign(rest, shapes, mapping, f);
+            mapping.remove(&letter);
+        }
+    }
+}
+
+/// A round of the guide as played, with its outcome and our points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Play {
+    pub round: Round,
+    pub outcome: Outcome,
+    pub points: u32,
+}
+
+/// Every round of a strategy guide as played, and the totals.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub plays: Vec<Play>,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub score: u32,
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "round  opponent  ours      outcome  points")?;
+        for (i, play) in self.plays.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>5}  {:<8}  {:<8}  {:<7}  {:>6}",
+                i + 1,
+                play.round.opponent,
+                play.round.ours,
+                play.outcome,
+                play.points
+            )?;
+        }
+        writeln!(
+            f,
+            "wins: {}, draws: {}, losses: {}, score: {}",
+            self.wins, self.draws, self.losses, self.score
+        )
+    }
+}
+
+/// Parse a strategy guide, one round per line: a letter of `opponent`, a space and a letter of
+/// `ours`.
+pub fn parse_guide(
+    input: &str,
+    opponent: &str,
+    ours: &str,
+) -> Result<Vec<(char, char)>, ParseError> {
+    let mut rounds = Vec::new();
+    for line in lines(Day02::DAY, input) {
+        let mut chars = line.text.chars();
+        match (chars.next(), chars.next(), chars.next(), chars.next()) {
+            (Some(a), Some(' '), Some(x), None) if opponent.contains(a) && ours.contains(x) => {
+                rounds.push((a, x))
+            }
+            _ => {
+                let expected = match (opponent.chars().next(), ours.chars().next()) {
+                    (Some(a), Some(x)) => format!("a round like `{} {}`", a, x),
+                    _ => String::from("a round"),
+                };
+                return Err(line.mismatch(expected));
+            }
+        }
+    }
+    Ok(rounds)
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    /// The strategy guide: opponent letter and our letter for each round.
+    type Input = Vec<(char, char)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_guide(input, "ABC", "XYZ")
+    }
+
+    fn part1(guide: &Self::Input) -> Answer {
+        let score = Engine::classic().score(&Strategy::shapes(), guide);
+        score.expect("the guide has A to C and X to Z").into()
+    }
+
+    fn part2(guide: &Self::Input) -> Answer {
+        let score = Engine::classic().score(&Strategy::outcomes(), guide);
+        score.expect("the guide has A to C and X to Z").into()
+    }
+}
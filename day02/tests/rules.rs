@@ -0,0 +1,115 @@
+use common::Solution;
+use day02::{parse_guide, Day02, Engine, Outcome, Round, Rules, Shape, Strategy};
+use std::collections::BTreeMap;
+
+const EXAMPLE: &str = include_str!("../data.test.txt");
+
+#[test]
+fn classic() {
+    let guide = Day02::parse(EXAMPLE).unwrap();
+    let engine = Engine::classic();
+    assert_eq!(engine.score(&Strategy::shapes(), &guide), Some(15));
+    assert_eq!(engine.score(&Strategy::outcomes(), &guide), Some(12));
+    assert_eq!(
+        engine.round(&Strategy::outcomes(), ('A', 'Y')),
+        Some(Round {
+            opponent: Shape::Rock,
+            ours: Shape::Rock
+        })
+    );
+    assert_eq!(engine.round(&Strategy::outcomes(), ('D', 'Y')), None);
+}
+
+#[test]
+fn lizard_spock() {
+    let rules = Rules::lizard_spock();
+    for opponent in rules.shapes() {
+        let outcomes: Vec<Outcome> = rules
+            .shapes()
+            .map(|ours| rules.outcome(Round { opponent, ours }))
+            .collect();
+        assert_eq!(outcomes.iter().filter(|&&o| o == Outcome::Win).count(), 2);
+        assert_eq!(outcomes.iter().filter(|&&o| o == Outcome::Draw).count(), 1);
+    }
+    let round = Round {
+        opponent: Shape::Spock,
+        ours: Shape::Lizard,
+    };
+    assert_eq!(rules.score(round), Some(4 + 6));
+    assert_eq!(
+        rules.respond(Shape::Spock, Outcome::Loss),
+        Some(Shape::Rock)
+    );
+    assert_eq!(Rules::classic().score(round), None);
+}
+
+#[test]
+fn custom_symbols() {
+    let engine = Engine {
+        rules: Rules::lizard_spock().with_outcome_points(0, 1, 2),
+        opponent: BTreeMap::from([('r', Shape::Rock), ('k', Shape::Spock)]),
+    };
+    let strategy = Strategy::Shapes(BTreeMap::from([('L', Shape::Lizard), ('P', Shape::Paper)]));
+    let guide = parse_guide("r L\nk P\nk L\n", "rk", "LP").unwrap();
+    assert_eq!(engine.score(&strategy, &guide), Some(4 + (2 + 2) + (4 + 2)));
+
+    let err = parse_guide("r L\nr X\n", "rk", "LP").unwrap_err();
+    assert_eq!(err.line_no, 2);
+    assert!(err.to_string().contains("a round like `r L`"), "{}", err);
+}
+
+#[test]
+fn report() {
+    let guide = Day02::parse(EXAMPLE).unwrap();
+    let report = Engine::classic()
+        .report(&Strategy::outcomes(), &guide)
+        .unwrap();
+    let plays: Vec<(Shape, Shape, Outcome, u32)> = report
+        .plays
+        .iter()
+        .map(|play| {
+            (
+                play.round.opponent,
+                play.round.ours,
+                play.outcome,
+                play.points,
+            )
+        })
+        .collect();
+    assert_eq!(
+        plays,
+        vec![
+            (Shape::Rock, Shape::Rock, Outcome::Draw, 4),
+            (Shape::Paper, Shape::Rock, Outcome::Loss, 1),
+            (Shape::Scissors, Shape::Rock, Outcome::Win, 7),
+        ]
+    );
+    assert_eq!((report.wins, report.draws, report.losses), (1, 1, 1));
+    assert_eq!(report.score, 12);
+}
+
+#[test]
+fn best_response() {
+    let guide = Day02::parse(EXAMPLE).unwrap();
+    let engine = Engine::classic();
+    assert_eq!(engine.best_score(&guide), Some(24));
+
+    let mappings = engine.mappings(&['X', 'Y', 'Z'], &guide);
+    assert_eq!(mappings.len(), 6);
+    let scores: Vec<u32> = mappings.iter().map(|&(_, score)| score).collect();
+    assert_eq!(scores, vec![24, 15, 15, 15, 15, 6]);
+    let best = BTreeMap::from([
+        ('X', Shape::Scissors),
+        ('Y', Shape::Paper),
+        ('Z', Shape::Rock),
+    ]);
+    assert_eq!(mappings[0].0, best);
+    let guide_as_shapes = Strategy::Shapes(mappings[0].0.clone());
+    assert_eq!(engine.score(&guide_as_shapes, &guide), Some(24));
+
+    let lizard_spock = Engine {
+        rules: Rules::lizard_spock(),
+        ..engine
+    };
+    assert_eq!(lizard_spock.mappings(&['X', 'Y', 'Z'], &guide).len(), 60);
+}
@@ -0,0 +1,20 @@
+//! The fewest swaps between compartments so that no rucksack has an item type in both, and
+//! the rucksacks once reorganized. The halves of a rucksack may share any number of types.
+//!
+//! cargo run -p day03 --example reorganize -- data.txt
+
+use day03::Day03;
+
+fn main() {
+    for (source, rucksacks) in common::parsed_inputs::<Day03>() {
+        let plan = day03::plan(&rucksacks).unwrap_or_else(|err| common::fail(err));
+        println!("{}: {} swaps", source, plan.swaps.len());
+        for swap in &plan.swaps {
+            println!("{}", swap);
+        }
+        println!();
+        for rucksack in &plan.rucksacks {
+            println!("{}", rucksack);
+        }
+    }
+}
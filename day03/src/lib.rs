@@ -0,0 +1,469 @@
+use common::{lines, Answer, Assumption, Line, ParseError, Solution};
+use std::fmt;
+
+pub struct Day03;
+
+pub fn to_priority(c: char) -> u32 {
+    let v = match c {
+        'A'..='Z' => c as u32 - 'A' as u32 + 27,
+        'a'..='z' => c as u32 - 'a' as u32 + 1,
+        _ => {
+            panic!("Invalid character {}", c);
+        }
+    };
+    v
+}
+
+pub fn from_priority(priority: u32) -> char {
+    match priority {
+        1..=26 => char::from(b'a' + (priority - 1) as u8),
+        27..=52 => char::from(b'A' + (priority - 27) as u8),
+        _ => panic!("Invalid priority {}", priority),
+    }
+}
+
+/// A set of item types, bit `p` standing for the item of priority `p`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Items(u64);
+
+impl Items {
+    pub fn new(items: &str) -> Items {
+        items.chars().collect()
+    }
+
+    /// Every item type.
+    pub fn all() -> Items {
+        Items(((1 << 52) - 1) << 1)
+    }
+
+    pub fn insert(&mut self, item: char) {
+        self.0 |= 1 << to_priority(item);
+    }
+
+    pub fn contains(self, item: char) -> bool {
+        self.0 & 1 << to_priority(item) != 0
+    }
+
+    pub fn intersection(self, other: Items) -> Items {
+        Items(self.0 & other.0)
+    }
+
+    pub fn union(self, other: Items) -> Items {
+        Items(self.0 | other.0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The items, by increasing priority.
+    pub fn iter(self) -> impl Iterator<Item = char> {
+        (1..=52)
+            .filter(move |&p| self.0 & 1 << p != 0)
+            .map(from_priority)
+    }
+}
+
+impl FromIterator<char> for Items {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Items {
+        let mut items = Items::default();
+        for item in iter {
+            items.insert(item);
+        }
+        items
+    }
+}
+
+impl fmt::Display for Items {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let items: Vec<String> = self.iter().map(|c| format!("`{}`", c)).collect();
+        write!(f, "{}", items.join(", "))
+    }
+}
+
+/// What shares an item: the compartments of a rucksack, or a group of rucksacks, numbered
+/// from 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sharing {
+    Rucksack(usize),
+    Group(usize),
+}
+
+impl fmt::Display for Sharing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Sharing::Rucksack(i) => write!(f, "the compartments of rucksack {}", i + 1),
+            Sharing::Group(i) => write!(f, "the rucksacks of group {}", i + 1),
+        }
+    }
+}
+
+/// Rucksacks, or a group of them, without a single item in common.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShareError {
+    /// The rucksack does not split into compartments of the same size.
+    Uneven {
+        rucksack: usize,
+        compartments: usize,
+    },
+    /// The last group is short of rucksacks.
+    Partial {
+        group: usize,
+        len: usize,
+        size: usize,
+    },
+    NoneShared(Sharing),
+    SeveralShared(Sharing, Items),
+}
+
+impl ShareError {
+    /// What was found instead of a single shared item, for the diagnostics.
+    fn found(&self) -> String {
+        match self {
+            ShareError::Uneven { compartments, .. } => {
+                format!("a size that is not a multiple of {}", compartments)
+            }
+            ShareError::Partial { len, .. } => format!("only {} rucksacks", len),
+            ShareError::NoneShared(_) => String::from("no common item"),
+            ShareError::SeveralShared(_, items) => format!("items {} in common", items),
+        }
+    }
+}
+
+impl fmt::Display for ShareError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShareError::Uneven {
+                rucksack,
+                compartments,
+            } => write!(
+                f,
+                "rucksack {} does not split into {} compartments",
+                rucksack + 1,
+                compartments
+            ),
+            ShareError::Partial { group, len, size } => write!(
+                f,
+                "group {} only has {} of {} rucksacks",
+                group + 1,
+                len,
+                size
+            ),
+            ShareError::NoneShared(sharing) => write!(f, "{} share no item", sharing),
+            ShareError::SeveralShared(sharing, items) => {
+                write!(f, "{} share items {}", sharing, items)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ShareError {}
+
+/// The items of the `n` compartments of `rucksack`, `None` when they cannot be the same size.
+pub fn compartments(rucksack: &str, n: usize) -> Option<Vec<Items>> {
+    if n == 0 || !rucksack.len().is_multiple_of(n) {
+        return None;
+    }
+    let size = rucksack.len() / n;
+    Some(
+        (0..n)
+            .map(|i| Items::new(&rucksack[i * size..][..size]))
+            .collect(),
+    )
+}
+
+/// The single item of every set of `items`.
+fn shared(items: impl IntoIterator<Item = Items>, sharing: Sharing) -> Result<char, ShareError> {
+    let common = items
+        .into_iter()
+        .fold(Items::all(), |common, items| common.intersection(items));
+    match common.len() {
+        0 => Err(ShareError::NoneShared(sharing)),
+        1 => Ok(common.iter().next().unwrap()),
+        _ => Err(ShareError::SeveralShared(sharing, common)),
+    }
+}
+
+/// The item in all the `compartments` of rucksack `index`.
+pub fn rucksack_item(
+    rucksacks: &[String],
+    index: usize,
+    compartments: usize,
+) -> Result<char, ShareError> {
+    let items = self::compartments(&rucksacks[index], compartments).ok_or(ShareError::Uneven {
+        rucksack: index,
+        compartments,
+    })?;
+    shared(items, Sharing::Rucksack(index))
+}
+
+/// The item in all the rucksacks of group `index`, the rucksacks taken `size` at a time.
+pub fn group_item(rucksacks: &[String], index: usize, size: usize) -> Result<char, ShareError> {
+    let group = &rucksacks[index * size..rucksacks.len().min((index + 1) * size)];
+    if group.len() < size {
+        return Err(ShareError::Partial {
+            group: index,
+            len: group.len(),
+            size,
+        });
+    }
+    let items = group.iter().map(|rucksack| Items::new(rucksack));
+    shared(items, Sharing::Group(index))
+}
+
+/// The item in all the `compartments` of each rucksack.
+///
+/// # Panics
+///
+/// When `compartments` is 0.
+pub fn compartment_items(
+    rucksacks: &[String],
+    compartments: usize,
+) -> Result<Vec<char>, ShareError> {
+    assert!(compartments > 0, "rucksacks have at least one compartment");
+    (0..rucksacks.len())
+        .map(|i| rucksack_item(rucksacks, i, compartments))
+        .collect()
+}
+
+/// The item in all the rucksacks of each group of `size` rucksacks, in input order.
+///
+/// # Panics
+///
+/// When `size` is 0.
+pub fn group_items(rucksacks: &[String], size: usize) -> Result<Vec<char>, ShareError> {
+    assert!(size > 0, "groups have at least one rucksack");
+    (0..rucksacks.len().div_ceil(size))
+        .map(|i| group_item(rucksacks, i, size))
+        .collect()
+}
+
+/// An exchange between the two compartments of a rucksack: the item at position `left` of the
+/// first one and the item at position `right` of the second one, both counted from the start
+/// of the rucksack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Swap {
+    pub rucksack: usize,
+    pub left: (usize, char),
+    pub right: (usize, char),
+}
+
+impl fmt::Display for Swap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "rucksack {}: swap `{}` at {} with `{}` at {}",
+            self.rucksack + 1,
+            self.left.1,
+            self.left.0 + 1,
+            self.right.1,
+            self.right.0 + 1
+        )
+    }
+}
+
+/// The swaps that leave no item type in both compartments of any rucksack, and the rucksacks
+/// once reorganized.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub swaps: Vec<Swap>,
+    pub rucksacks: Vec<String>,
+}
+
+/// A rucksack whose compartments cannot be made to share no item type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanError {
+    /// The rucksack has an odd number of items.
+    Uneven { rucksack: usize },
+    /// No set of item types fills exactly half of the rucksack.
+    Impossible { rucksack: usize },
+}
+
+impl fmt::Display for PlanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PlanError::Uneven { rucksack } => {
+                write!(f, "rucksack {} has an odd number of items", rucksack + 1)
+            }
+            PlanError::Impossible { rucksack } => write!(
+                f,
+                "the items of rucksack {} cannot be split in two halves of distinct types",
+                rucksack + 1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PlanError {}
+
+/// The fewest swaps between the compartments of rucksack `index` so that they share no item
+/// type.
+///
+/// Every type ends up whole in one compartment: picking the types of the first one so that
+/// they fill half of the rucksack, with as few of their items as possible in the second one,
+/// is a knapsack over the types. One swap brings one of those items over.
+pub fn plan_rucksack(rucksacks: &[String], index: usize) -> Result<Vec<Swap>, PlanError> {
+    let rucksack = &rucksacks[index];
+    if !rucksack.len().is_multiple_of(2) {
+        return Err(PlanError::Uneven { rucksack: index });
+    }
+    let half = rucksack.len() / 2;
+    // Items of each type in the first and the second compartment, by priority
+    let mut counts = [(0, 0); 53];
+    for (i, c) in rucksack.chars().enumerate() {
+        let count = &mut counts[to_priority(c) as usize];
+        if i < half {
+            count.0 += 1;
+        } else {
+            count.1 += 1;
+        }
+    }
+    let types: Vec<usize> = (1..=52).filter(|&p| counts[p] != (0, 0)).collect();
+    // fewest[t][n]: fewest items to bring over to fill n places of the first compartment
+    // with whole types among the first t
+    let mut fewest = vec![vec![None; half + 1]; types.len() + 1];
+    fewest[0][0] = Some(0);
+    for (t, &p) in types.iter().enumerate() {
+        let (first, second) = counts[p];
+        for n in 0..=half {
+            let skip = fewest[t][n];
+            let take = n
+                .checked_sub(first + second)
+                .and_then(|rest| fewest[t][rest])
+                .map(|moved: usize| moved + second);
+            fewest[t + 1][n] = match (skip, take) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            };
+        }
+    }
+    if fewest[types.len()][half].is_none() {
+        return Err(PlanError::Impossible { rucksack: index });
+    }
+    let mut in_first = Items::default();
+    let mut n = half;
+    for (t, &p) in types.iter().enumerate().rev() {
+        if fewest[t + 1][n] != fewest[t][n] {
+            in_first.insert(from_priority(p as u32));
+            n -= counts[p].0 + counts[p].1;
+        }
+    }
+    let items: Vec<(usize, char)> = rucksack.chars().enumerate().collect();
+    let (first, second) = items.split_at(half);
+    let outgoing = first.iter().filter(|&&(_, c)| !in_first.contains(c));
+    let incoming = second.iter().filter(|&&(_, c)| in_first.contains(c));
+    Ok(outgoing
+        .zip(incoming)
+        .map(|(&left, &right)| Swap {
+            rucksack: index,
+            left,
+            right,
+        })
+        .collect())
+}
+
+/// The fewest swaps to reorganize every rucksack, see [`plan_rucksack`].
+pub fn plan(rucksacks: &[String]) -> Result<Plan, PlanError> {
+    let mut swaps = Vec::new();
+    let mut fixed = Vec::with_capacity(rucksacks.len());
+    for index in 0..rucksacks.len() {
+        let mut items: Vec<char> = rucksacks[index].chars().collect();
+        for swap in plan_rucksack(rucksacks, index)? {
+            items.swap(swap.left.0, swap.right.0);
+            swaps.push(swap);
+        }
+        fixed.push(items.into_iter().collect());
+    }
+    Ok(Plan {
+        swaps,
+        rucksacks: fixed,
+    })
+}
+
+/// The error at the line of the rucksack, or of the first rucksack of the group of three, at
+/// fault.
+fn share_error(rucksacks: &[String], err: ShareError) -> ParseError {
+    let (index, expected) = match err {
+        ShareError::Uneven { rucksack, .. }
+        | ShareError::NoneShared(Sharing::Rucksack(rucksack))
+        | ShareError::SeveralShared(Sharing::Rucksack(rucksack), _) => (
+            rucksack,
+            "two compartments of the same size sharing a single item",
+        ),
+        ShareError::Partial { group, .. }
+        | ShareError::NoneShared(Sharing::Group(group))
+        | ShareError::SeveralShared(Sharing::Group(group), _) => (
+            group * 3,
+            "a group of three rucksacks sharing a single item",
+        ),
+    };
+    let line = Line {
+        day: Day03::DAY,
+        number: index + 1,
+        text: &rucksacks[index],
+    };
+    line.error(1, expected, err.found())
+}
+
+fn priorities(rucksacks: &[String], items: Result<Vec<char>, ShareError>) -> Answer {
+    let items = items.unwrap_or_else(|err| common::reject(share_error(rucksacks, err)));
+    items.into_iter().map(to_priority).sum::<u32>().into()
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    /// One rucksack per line.
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut rucksacks = Vec::new();
+        for line in lines(Self::DAY, input) {
+            if let Some((i, c)) = line
+                .text
+                .char_indices()
+                .find(|(_, c)| !c.is_ascii_alphabetic())
+            {
+                return Err(line.error(i + 1, "an item letter", format!("`{}`", c)));
+            }
+            rucksacks.push(line.text.to_string());
+        }
+        Ok(rucksacks)
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Answer {
+        priorities(rucksacks, compartment_items(rucksacks, 2))
+    }
+
+    fn part2(rucksacks: &Self::Input) -> Answer {
+        priorities(rucksacks, group_items(rucksacks, 3))
+    }
+
+    fn check(input: &str) -> Vec<Assumption> {
+        let Ok(rucksacks) = Self::parse(input) else {
+            return Vec::new();
+        };
+        let compartments = (0..rucksacks.len())
+            .filter_map(|i| rucksack_item(&rucksacks, i, 2).err())
+            .map(|err| share_error(&rucksacks, err))
+            .collect();
+        let groups = (0..rucksacks.len().div_ceil(3))
+            .filter_map(|i| group_item(&rucksacks, i, 3).err())
+            .map(|err| share_error(&rucksacks, err))
+            .collect();
+        vec![
+            Assumption::new(
+                "each rucksack splits in two compartments sharing exactly one item",
+                compartments,
+            ),
+            Assumption::new(
+                "the rucksacks come in groups of three sharing exactly one item",
+                groups,
+            ),
+        ]
+    }
+}
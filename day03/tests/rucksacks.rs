@@ -0,0 +1,206 @@
+use common::{Solution, SolveError};
+use day03::{
+    compartment_items, group_item, group_items, rucksack_item, Day03, Items, PlanError, ShareError,
+    Sharing, Swap,
+};
+use std::collections::HashSet;
+
+const EXAMPLE: &str = include_str!("../data.test.txt");
+
+#[test]
+fn items() {
+    let items = Items::new("ZabaA");
+    assert_eq!(items.len(), 4);
+    assert!(items.contains('Z') && !items.contains('z'));
+    assert_eq!(items.iter().collect::<String>(), "abAZ");
+    assert_eq!(items.intersection(Items::new("bZz")), Items::new("Zb"));
+    assert_eq!(Items::all().len(), 52);
+    assert_eq!(Items::new("Ab").to_string(), "`b`, `A`");
+}
+
+#[test]
+fn shared_items() {
+    let rucksacks = Day03::parse(EXAMPLE).unwrap();
+    assert_eq!(
+        compartment_items(&rucksacks, 2).unwrap(),
+        ['p', 'L', 'P', 'v', 't', 's']
+    );
+    assert_eq!(group_items(&rucksacks, 3).unwrap(), ['r', 'Z']);
+    assert_eq!(
+        compartment_items(&rucksacks, 3),
+        Err(ShareError::NoneShared(Sharing::Rucksack(0)))
+    );
+    assert!(matches!(
+        group_items(&rucksacks, 2),
+        Err(ShareError::SeveralShared(Sharing::Group(0), _))
+    ));
+}
+
+#[test]
+fn malformed_groups() {
+    let rucksacks = Day03::parse(EXAMPLE).unwrap();
+    assert_eq!(
+        group_items(&rucksacks[..4], 3),
+        Err(ShareError::Partial {
+            group: 1,
+            len: 1,
+            size: 3
+        })
+    );
+    assert_eq!(group_item(&rucksacks[..4], 0, 3), Ok('r'));
+    assert_eq!(
+        rucksack_item(&[String::from("abc")], 0, 2),
+        Err(ShareError::Uneven {
+            rucksack: 0,
+            compartments: 2
+        })
+    );
+}
+
+#[test]
+fn rejected_by_the_parts() {
+    let errors = |input: &str, part: u8| match common::solve::<Day03>(input, &[part]) {
+        Err(SolveError::Parse(err)) => (err.line_no, err.column, err.expected, err.found),
+        other => panic!("expected a diagnostic, got {:?}", other),
+    };
+    let rucksack = "two compartments of the same size sharing a single item";
+    let group = "a group of three rucksacks sharing a single item";
+    assert_eq!(
+        errors("\n\n", 1),
+        (1, 1, rucksack.into(), "no common item".into())
+    );
+    assert_eq!(
+        errors("vJrwpWtwJgWrhcsFMMfFFhFp\nabc\n", 1),
+        (
+            2,
+            1,
+            rucksack.into(),
+            "a size that is not a multiple of 2".into()
+        )
+    );
+    let four: String = EXAMPLE
+        .lines()
+        .take(4)
+        .map(|l| format!("{}\n", l))
+        .collect();
+    assert_eq!(
+        errors(&four, 2),
+        (4, 1, group.into(), "only 1 rucksacks".into())
+    );
+    assert_eq!(
+        errors("abcb\nabdb\nabeb\n", 2),
+        (1, 1, group.into(), "items `a`, `b` in common".into())
+    );
+
+    // Part 1 does not need whole groups, nor part 2 a single item per rucksack
+    let answers = common::solve::<Day03>(&four, &[1]).unwrap();
+    assert_eq!(answers[0].answer.to_string(), "118");
+    let answers = common::solve::<Day03>("abab\nacac\nadad\n", &[2]).unwrap();
+    assert_eq!(answers[0].answer.to_string(), "1");
+    assert_eq!(Day03::parse("ab1\n").unwrap_err().column, 3);
+    assert_eq!(Day03::parse(""), Ok(vec![]));
+}
+
+#[test]
+fn check() {
+    assert!(Day03::check(EXAMPLE).iter().all(|a| a.holds()));
+    let assumptions = Day03::check(&EXAMPLE.replace("ttgJtRGJQctTZtZT\n", "\n"));
+    let lines: Vec<Vec<usize>> = assumptions
+        .iter()
+        .map(|a| a.violations.iter().map(|e| e.line_no).collect())
+        .collect();
+    assert_eq!(lines, [vec![5], vec![4]]);
+}
+
+fn disjoint(rucksack: &str) -> bool {
+    let halves = day03::compartments(rucksack, 2).unwrap();
+    halves[0].intersection(halves[1]).is_empty()
+}
+
+/// Fewest swaps between the halves of `rucksack` to make them disjoint, by breadth first search.
+fn fewest_swaps(rucksack: &str) -> Option<usize> {
+    let half = rucksack.len() / 2;
+    let mut seen = HashSet::from([rucksack.to_string()]);
+    let mut frontier = vec![rucksack.to_string()];
+    for swaps in 0.. {
+        if frontier.is_empty() {
+            return None;
+        }
+        if frontier.iter().any(|r| disjoint(r)) {
+            return Some(swaps);
+        }
+        let mut next = Vec::new();
+        for r in frontier {
+            for i in 0..half {
+                for j in half..r.len() {
+                    let mut items: Vec<char> = r.chars().collect();
+                    items.swap(i, j);
+                    let swapped: String = items.into_iter().collect();
+                    if seen.insert(swapped.clone()) {
+                        next.push(swapped);
+                    }
+                }
+            }
+        }
+        frontier = next;
+    }
+    unreachable!()
+}
+
+#[test]
+fn plan() {
+    let rucksacks = Day03::parse(EXAMPLE).unwrap();
+    let plan = day03::plan(&rucksacks).unwrap();
+    assert_eq!(plan.swaps.len(), 9);
+    assert!(plan.rucksacks.iter().all(|r| disjoint(r)));
+    for (before, after) in rucksacks.iter().zip(&plan.rucksacks) {
+        let mut before: Vec<char> = before.chars().collect();
+        let mut after: Vec<char> = after.chars().collect();
+        before.sort_unstable();
+        after.sort_unstable();
+        assert_eq!(before, after);
+    }
+    assert_eq!(
+        plan.swaps[0],
+        Swap {
+            rucksack: 0,
+            left: (0, 'v'),
+            right: (23, 'p')
+        }
+    );
+}
+
+#[test]
+fn plan_parsed_rucksacks() {
+    // Halves sharing several types, or none, are what the planner is for
+    let rucksacks = Day03::parse("abab\nabab\nabab\nabcd\n").unwrap();
+    let plan = day03::plan(&rucksacks).unwrap();
+    assert_eq!(plan.swaps.len(), 3);
+    assert_eq!(plan.rucksacks, ["aabb", "aabb", "aabb", "abcd"]);
+}
+
+#[test]
+fn plan_is_minimal() {
+    for rucksack in [
+        "abab", "aabb", "abcabc", "aabbcc", "abacbc", "abcdcd", "aaabbb",
+    ] {
+        let rucksacks = [rucksack.to_string()];
+        let swaps = day03::plan_rucksack(&rucksacks, 0).ok();
+        let planned = swaps.map(|swaps| swaps.len());
+        assert_eq!(planned, fewest_swaps(rucksack), "{}", rucksack);
+    }
+}
+
+#[test]
+fn unplannable() {
+    let rucksacks = [String::from("aaab"), String::from("abc")];
+    assert_eq!(
+        day03::plan(&rucksacks),
+        Err(PlanError::Impossible { rucksack: 0 })
+    );
+    assert_eq!(
+        day03::plan_rucksack(&rucksacks, 1),
+        Err(PlanError::Uneven { rucksack: 1 })
+    );
+    assert_eq!(fewest_swaps("aaab"), None);
+}
@@ -0,0 +1,26 @@
+//! Number of elves assigned each run of sections, the sections nobody is assigned, and the
+//! elves whose assignments are redundant with the others.
+//!
+//! cargo run -p day04 --example coverage -- data.txt
+
+use day04::{Coverage, Day04};
+
+fn main() {
+    for (source, pairs) in common::parsed_inputs::<Day04>() {
+        let coverage = Coverage::new(&pairs);
+        println!("{}:", source);
+        println!("sections    elves");
+        for &(first, last, depth) in coverage.runs() {
+            println!("{:>4}-{:<4}  {:>5}", first, last, depth);
+        }
+        println!("uncovered: {}", coverage.uncovered());
+        println!("maximum overlap: {} elves", coverage.max_depth());
+        let cover = coverage.minimal_cover();
+        println!(
+            "minimal cover: {} of {} elves, {} redundant",
+            cover.len(),
+            pairs.len() * 2,
+            coverage.redundant().len()
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use gcollections::ops::{Difference, Empty, Overlap};
+use interval::interval_set::{IntervalSet, ToIntervalSet};
+use interval::ops::*;
+use interval::Interval;
+use regex::Regex;
+
+use common::{lines, Answer, ParseError, Solution};
+
+pub struct Day04;
+
+/// Section assignments of a pair of elves, as inclusive `(min, max)` ranges.
+pub type Pair = ((u32, u32), (u32, u32));
+
+/// Number of elves assigned each section, from a sweep over the ends of the assignments.
+///
+/// Elves are numbered from 0 in input order, two per pair. An assignment whose first section
+/// comes after its last one is empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coverage {
+    elves: Vec<(u32, u32)>,
+    /// First and last section of each run of sections assigned to as many elves, and that
+    /// number, from the first assigned section to the last one.
+    runs: Vec<(u32, u32, usize)>,
+}
+
+impl Coverage {
+    pub fn new(pairs: &[Pair]) -> Coverage {
+        let elves: Vec<(u32, u32)> = pairs.iter().flat_map(|&(a, b)| [a, b]).collect();
+        // Depth changes at the first section of each assignment and after its last one
+        let mut events: Vec<(u64, isize)> = elves
+            .iter()
+            .filter(|&&(min, max)| min <= max)
+            .flat_map(|&(min, max)| [(min as u64, 1), (max as u64 + 1, -1)])
+            .collect();
+        events.sort_unstable();
+        let mut runs: Vec<(u32, u32, usize)> = Vec::new();
+        let mut depth = 0isize;
+        let mut i = 0;
+        while i < events.len() {
+            let section = events[i].0;
+            while i < events.len() && events[i].0 == section {
+                depth += events[i].1;
+                i += 1;
+            }
+            let Some(&(next, _)) = events.get(i) else {
+                break;
+            };
+            let run = (section as u32, (next - 1) as u32, depth as usize);
+            match runs.last_mut() {
+                Some(last) if last.2 == run.2 => last.1 = run.1,
+                _ => runs.push(run),
+            }
+        }
+        Coverage { elves, runs }
+    }
+
+    /// Runs of consecutive sections assigned to the same number of elves, as `(first, last,
+    /// elves)`, in order. Gaps between assignments are runs of 0 elves.
+    pub fn runs(&self) -> &[(u32, u32, usize)] {
+        &self.runs
+    }
+
+    /// Number of elves assigned `section`.
+    pub fn depth(&self, section: u32) -> usize {
+        let i = self.runs.partition_point(|&(_, last, _)| last < section);
+        match self.runs.get(i) {
+            Some(&(first, _, depth)) if first <= section => depth,
+            _ => 0,
+        }
+    }
+
+    pub fn max_depth(&self) -> usize {
+        self.runs
+            .iter()
+            .map(|&(_, _, depth)| depth)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The sections assigned to at least one elf.
+    pub fn assigned(&self) -> IntervalSet<u32> {
+        let assigned: Vec<(u32, u32)> = self
+            .elves
+            .iter()
+            .copied()
+            .filter(|&(min, max)| min <= max)
+            .collect();
+        assigned.to_interval_set()
+    }
+
+    /// The sections between the first and the last assigned ones that no elf is assigned.
+    pub fn uncovered(&self) -> IntervalSet<u32> {
+        let assigned = self.assigned();
+        match (self.runs.first(), self.runs.last()) {
+            (Some(&(first, _, _)), Some(&(_, last, _))) => {
+                (first, last).to_interval_set().difference(&assigned)
+            }
+            _ => IntervalSet::empty(),
+        }
+    }
+
+    /// The fewest elves whose assignments still cover every assigned section, in order.
+    ///
+    /// Greedily, the first section not yet covered goes to the elf that reaches furthest among
+    /// those whose assignment starts there or before.
+    pub fn minimal_cover(&self) -> Vec<usize> {
+        let mut elves: Vec<usize> = (0..self.elves.len())
+            .filter(|&i| self.elves[i].0 <= self.elves[i].1)
+            .collect();
+        elves.sort_by_key(|&i| self.elves[i]);
+        let mut cover = Vec::new();
+        let mut candidates = elves.into_iter().peekable();
+        // First section not yet covered
+        let mut next = 0u64;
+        while let Some(&first) = candidates.peek() {
+            next = next.max(self.elves[first].0 as u64);
+            let mut best: Option<usize> = None;
+            while let Some(i) = candidates.next_if(|&i| self.elves[i].0 as u64 <= next) {
+                if best.is_none_or(|best| self.elves[i].1 > self.elves[best].1) {
+                    best = Some(i);
+                }
+            }
+            let best = best.unwrap();
+            if self.elves[best].1 as u64 >= next {
+                cover.push(best);
+                next = self.elves[best].1 as u64 + 1;
+            }
+        }
+        cover.sort_unstable();
+        cover
+    }
+
+    /// The elves left out of [`Coverage::minimal_cover`]: their sections are all assigned to
+    /// other elves of the cover.
+    pub fn redundant(&self) -> Vec<usize> {
+        let cover = self.minimal_cover();
+        (0..self.elves.len())
+            .filter(|i| cover.binary_search(i).is_err())
+            .collect()
+    }
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Pair>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let re = Regex::new(r"^(\d+)-(\d+),(\d+)-(\d+)$").unwrap();
+        let mut pairs = Vec::new();
+        for line in lines(Self::DAY, input) {
+            let cap = re
+                .captures(line.text)
+                .ok_or_else(|| line.mismatch("a pair of ranges like `2-4,6-8`"))?;
+            let section = |i| line.parse::<u32>(cap.get(i).unwrap().as_str(), "a section number");
+            let min1 = section(1)?;
+            let max1 = section(2)?;
+            let min2 = section(3)?;
+            let max2 = section(4)?;
+            pairs.push(((min1, max1), (min2, max2)));
+        }
+        Ok(pairs)
+    }
+
+    fn part1(pairs: &Self::Input) -> Answer {
+        let mut count = 0u32;
+        for &((min1, max1), (min2, max2)) in pairs {
+            if (min1 <= min2 && max1 >= max2) || (min1 >= min2 && max1 <= max2) {
+                count += 1;
+            }
+        }
+        count.into()
+    }
+
+    fn part2(pairs: &Self::Input) -> Answer {
+        let mut count = 0u32;
+        for &((min1, max1), (min2, max2)) in pairs {
+            let i1 = Interval::new(min1, max1);
+            let i2 = Interval::new(min2, max2);
+            if i1.overlap(&i2) || i2.overlap(&i1) {
+                count += 1;
+            }
+        }
+        count.into()
+    }
+}
@@ -0,0 +1,85 @@
+use common::Solution;
+use day04::{Coverage, Day04, Pair};
+use interval::prelude::*;
+
+const EXAMPLE: &str = include_str!("../data.test.txt");
+
+fn elves(pairs: &[Pair]) -> Vec<(u32, u32)> {
+    pairs.iter().flat_map(|&(a, b)| [a, b]).collect()
+}
+
+fn covers(elves: &[(u32, u32)], section: u32) -> usize {
+    elves
+        .iter()
+        .filter(|&&(min, max)| min <= section && section <= max)
+        .count()
+}
+
+#[test]
+fn depths() {
+    let pairs = Day04::parse(EXAMPLE).unwrap();
+    let coverage = Coverage::new(&pairs);
+    for section in 0..12 {
+        assert_eq!(
+            coverage.depth(section),
+            covers(&elves(&pairs), section),
+            "section {}",
+            section
+        );
+    }
+    assert_eq!(coverage.runs().first(), Some(&(2, 2, 4)));
+    assert_eq!(coverage.max_depth(), 8);
+    assert!(coverage.uncovered().is_empty());
+}
+
+#[test]
+fn gaps() {
+    let pairs = [((1, 2), (5, 6)), ((2, 3), (8, 8)), ((9, 10), (4, 2))];
+    let coverage = Coverage::new(&pairs);
+    assert_eq!(coverage.uncovered(), [(4, 4), (7, 7)].to_interval_set());
+    assert_eq!(
+        coverage.assigned(),
+        [(1, 3), (5, 6), (8, 10)].to_interval_set()
+    );
+    assert_eq!(coverage.depth(4), 0);
+    assert_eq!(coverage.runs()[3], (4, 4, 0));
+    assert_eq!(coverage.minimal_cover(), [0, 1, 2, 3, 4]);
+    assert_eq!(coverage.redundant(), [5]);
+    assert_eq!(Coverage::new(&[]).max_depth(), 0);
+}
+
+#[test]
+fn minimal_cover() {
+    let pairs = Day04::parse(EXAMPLE).unwrap();
+    let coverage = Coverage::new(&pairs);
+    assert_eq!(coverage.minimal_cover(), [5, 6]);
+    assert_eq!(coverage.redundant().len(), 10);
+
+    // Against every subset of a few overlapping and adjacent assignments
+    let pairs = [
+        ((1, 3), (2, 5)),
+        ((4, 4), (6, 9)),
+        ((5, 7), (3, 6)),
+        ((8, 9), (1, 1)),
+    ];
+    let elves = elves(&pairs);
+    let assigned = |subset: &[(u32, u32)]| (0..12).filter(|&s| covers(subset, s) > 0).count();
+    let fewest = (0u32..1 << elves.len())
+        .filter(|mask| {
+            let subset: Vec<(u32, u32)> = (0..elves.len())
+                .filter(|i| mask & 1 << i != 0)
+                .map(|i| elves[i])
+                .collect();
+            assigned(&subset) == assigned(&elves)
+        })
+        .map(u32::count_ones)
+        .min()
+        .unwrap();
+    let cover: Vec<(u32, u32)> = Coverage::new(&pairs)
+        .minimal_cover()
+        .into_iter()
+        .map(|i| elves[i])
+        .collect();
+    assert_eq!(cover.len(), fewest as usize);
+    assert_eq!(assigned(&cover), assigned(&elves));
+}
@@ -0,0 +1,154 @@
+use regex::Regex;
+use std::collections::LinkedList;
+use std::vec::Vec;
+
+use common::{lines, Answer, ParseError, Solution};
+
+pub struct Day05;
+
+/// Move `count` crates from stack `from` to stack `to` (zero-based).
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Move {
+    count: u32,
+    from: usize,
+    to: usize,
+}
+
+/// Starting stacks, top crate first, and the rearrangement procedure.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Procedure {
+    stacks: Vec<LinkedList<char>>,
+    moves: Vec<Move>,
+}
+
+fn process_part_1(vec: &mut [LinkedList<char>], from: usize, to: usize, count: u32) {
+    for _i in 0..count {
+        let bx = vec[from].pop_front().unwrap();
+        vec[to].push_front(bx);
+    }
+}
+
+fn process_part_2(vec: &mut [LinkedList<char>], from: usize, to: usize, count: u32) {
+    let mut tmp = LinkedList::new();
+    for _i in 0..count {
+        let bx = vec[from].pop_front().unwrap();
+        tmp.push_front(bx);
+    }
+    for _i in 0..count {
+        let bx = tmp.pop_front().unwrap();
+        vec[to].push_front(bx);
+    }
+}
+
+fn process(
+    procedure: &Procedure,
+    processor: fn(vec: &mut [LinkedList<char>], from: usize, to: usize, count: u32),
+) -> String {
+    let mut vec = procedure.stacks.clone();
+    for m in &procedure.moves {
+        let _step = common::span!("move", count = m.count, from = m.from + 1, to = m.to + 1);
+        processor(&mut vec, m.from, m.to, m.count);
+        common::trace!(stacks = ?vec);
+    }
+    // Stacks left empty have no crate on top to read
+    vec.iter().filter_map(|list| list.front()).collect()
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Procedure;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let first = input.lines().next().unwrap_or("");
+        let stack_count = (first.len() + 1) / 4;
+        let mut stacks = Vec::new();
+        for _ in 0..stack_count {
+            let stack = LinkedList::new();
+            stacks.push(stack);
+        }
+        let mut moves = Vec::new();
+        // Crates on each stack as the moves go, counted once the crates are read
+        let mut heights: Vec<usize> = Vec::new();
+        let re = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
+        for line in lines(Self::DAY, input) {
+            match line.text {
+                l if l.contains('[') => {
+                    common::trace!(line = l, "crates");
+
+                    for (i, stack) in stacks.iter_mut().enumerate() {
+                        match l.chars().nth(i * 4 + 1) {
+                            Some(c) if c.is_alphabetic() => {
+                                stack.push_back(c);
+                            }
+                            Some(' ') | None => {}
+                            Some(c) => {
+                                return Err(line.error(
+                                    i * 4 + 2,
+                                    "a crate letter",
+                                    format!("`{}`", c),
+                                ));
+                            }
+                        }
+                    }
+                }
+                l if l.starts_with("move") => {
+                    let cap = re
+                        .captures(l)
+                        .ok_or_else(|| line.mismatch("a move like `move 1 from 2 to 1`"))?;
+                    let count = line.parse::<u32>(cap.get(1).unwrap().as_str(), "a crate count")?;
+                    let from =
+                        line.parse::<usize>(cap.get(2).unwrap().as_str(), "a stack number")?;
+                    let to = line.parse::<usize>(cap.get(3).unwrap().as_str(), "a stack number")?;
+                    if heights.is_empty() {
+                        heights = stacks.iter().map(|stack| stack.len()).collect();
+                    }
+                    if from == 0 || from > stack_count {
+                        return Err(line.error_at(
+                            cap.get(2).unwrap().as_str(),
+                            format!("a stack between 1 and {}", stack_count),
+                        ));
+                    }
+                    if to == 0 || to > stack_count {
+                        return Err(line.error_at(
+                            cap.get(3).unwrap().as_str(),
+                            format!("a stack between 1 and {}", stack_count),
+                        ));
+                    }
+                    let height = heights[from - 1];
+                    if count as usize > height {
+                        return Err(line.error_at(
+                            cap.get(1).unwrap().as_str(),
+                            format!("at most {} crates, the height of stack {}", height, from),
+                        ));
+                    }
+                    heights[from - 1] -= count as usize;
+                    heights[to - 1] += count as usize;
+                    moves.push(Move {
+                        count,
+                        from: from - 1,
+                        to: to - 1,
+                    });
+                }
+                // The stack numbers under the crates, and the line before the moves
+                l if l.split_whitespace().all(|n| n.parse::<usize>().is_ok()) => {}
+                _ => {
+                    return Err(line.mismatch(
+                        "a row of crates, the stack numbers or a move like `move 1 from 2 to 1`",
+                    ))
+                }
+            }
+        }
+        Ok(Procedure { stacks, moves })
+    }
+
+    fn part1(procedure: &Self::Input) -> Answer {
+        process(procedure, process_part_1).into()
+    }
+
+    fn part2(procedure: &Self::Input) -> Answer {
+        process(procedure, process_part_2).into()
+    }
+}
@@ -0,0 +1,46 @@
+use common::Solution;
+use day05::Day05;
+
+const EXAMPLE: &str = include_str!("../data.test.txt");
+
+fn error(input: &str) -> (usize, usize, String, String) {
+    let err = Day05::parse(input).unwrap_err();
+    (err.line_no, err.column, err.expected, err.found)
+}
+
+#[test]
+fn unknown_lines() {
+    let (line, column, _, found) = error(&format!("{}\nhello world\n", EXAMPLE));
+    assert_eq!((line, column, found.as_str()), (10, 1, "`hello`"));
+    assert_eq!(
+        error(&EXAMPLE.replace(" 1   2   3 ", " 1   2   x ")).3,
+        "`1`"
+    );
+}
+
+#[test]
+fn impossible_moves() {
+    let input = EXAMPLE.replace("move 3 from 1 to 3", "move 4 from 1 to 3");
+    assert_eq!(
+        error(&input),
+        (
+            7,
+            6,
+            "at most 3 crates, the height of stack 1".into(),
+            "`4`".into()
+        )
+    );
+    // A single crate is left on stack 2 after the example moves
+    let input = format!("{}\nmove 2 from 2 to 3\n", EXAMPLE);
+    assert_eq!(error(&input).0, 10);
+    let input = format!("{}\nmove 1 from 2 to 3\n", EXAMPLE);
+    assert!(Day05::parse(&input).is_ok());
+}
+
+#[test]
+fn empty_stacks() {
+    // Stacks 1 and 2 end up empty
+    let input = EXAMPLE.replace("move 1 from 1 to 2", "move 2 from 1 to 3");
+    let procedure = Day05::parse(&input).unwrap();
+    assert_eq!(Day05::part1(&procedure).to_string(), "C");
+}
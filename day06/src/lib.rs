@@ -0,0 +1,56 @@
+use common::{lines, Answer, ParseError, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    /// The datastream buffer.
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut buffer = None;
+        for line in lines(Self::DAY, input.trim_end()) {
+            if buffer.is_some() {
+                return Err(line.mismatch("a single line"));
+            }
+            if let Some((i, c)) = line
+                .text
+                .char_indices()
+                .find(|(_, c)| !c.is_ascii_lowercase())
+            {
+                return Err(line.error(i + 1, "a lowercase letter", format!("`{}`", c)));
+            }
+            buffer = Some(line.text.to_string());
+        }
+        buffer.ok_or_else(|| ParseError::end_of_input(Self::DAY, input, "a datastream"))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        for i in 4..input.len() {
+            let c1 = input.chars().nth(i - 4).unwrap();
+            let c2 = input.chars().nth(i - 3).unwrap();
+            let c3 = input.chars().nth(i - 2).unwrap();
+            let c4 = input.chars().nth(i - 1).unwrap();
+            if c1 != c2 && c1 != c3 && c1 != c4 && c2 != c3 && c2 != c4 && c3 != c4 {
+                return (i as u32).into();
+            }
+        }
+        0u32.into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let mut i = 14;
+        'outer: while i < input.len() {
+            for j in (1..=14).rev() {
+                let c = input.chars().nth(i - j).unwrap();
+                if input[i - j + 1..i].contains(c) {
+                    i = i - j + 15;
+                    continue 'outer;
+                }
+            }
+            return (i as u32).into();
+        }
+        0u32.into()
+    }
+}
@@ -0,0 +1,131 @@
+use regex::Regex;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::vec::Vec;
+
+use common::overflow::checked;
+use common::{lines, Answer, ParseError, Solution};
+use num_traits::{CheckedAdd, CheckedSub};
+
+pub struct Day07;
+
+#[cfg(not(feature = "bigint"))]
+type Size = u32;
+#[cfg(feature = "bigint")]
+type Size = num_bigint::BigUint;
+
+const DISK: u32 = 70000000;
+const UPDATE: u32 = 30000000;
+
+/// Every directory visited, and the size of every file keyed by its full path.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Filesystem {
+    directories: HashSet<String>,
+    files: HashMap<String, Size>,
+}
+
+fn dir_size(dir: &str, files: &HashMap<String, Size>) -> Size {
+    files
+        .iter()
+        .filter(|&(f, _s)| f.starts_with(dir))
+        .fold(Size::default(), |acc, (_, size)| {
+            let sum = CheckedAdd::checked_add(&acc, size);
+            checked(sum, Day07::DAY, || {
+                format!("size of {} {} + {}", dir, acc, size)
+            })
+        })
+}
+
+fn parse(input: &str) -> Result<Filesystem, ParseError> {
+    let mut directories = HashSet::new();
+    let mut files = HashMap::new();
+    let mut working_dir = Vec::new();
+    let re_cmd = Regex::new(r"^\$ (cd|ls)\s*(.*)$").unwrap();
+    let re_file = Regex::new(r"^(\d+) (.+)$").unwrap();
+    for line in lines(Day07::DAY, input) {
+        match line.text {
+            cmd if cmd.starts_with('$') => {
+                let tmp = re_cmd
+                    .captures(cmd)
+                    .ok_or_else(|| line.error_at(&cmd[1..], "`cd` or `ls`"))?;
+                let cmd = tmp.get(1).unwrap().as_str();
+                let arg = tmp.get(2).unwrap().as_str();
+                match cmd {
+                    "ls" => {
+                        common::trace!(dir = working_dir.join("_"), "listing");
+                    }
+                    "cd" if arg == ".." => {
+                        working_dir.pop();
+                        common::trace!("cd to parent dir");
+                    }
+                    "cd" => {
+                        working_dir.push(arg);
+                        directories.insert(working_dir.join("_"));
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            dir if dir.starts_with("dir") => {}
+            file => {
+                let tmp = re_file.captures(file).ok_or_else(|| {
+                    line.mismatch("a command, a directory or a file like `14848514 b.txt`")
+                })?;
+                let size = line.parse::<Size>(tmp.get(1).unwrap().as_str(), "a file size")?;
+                let filename = tmp.get(2).unwrap().as_str();
+                files.insert(format!("{}+{}", working_dir.join("_"), filename), size);
+            }
+        }
+    }
+    Ok(Filesystem { directories, files })
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Filesystem;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(fs: &Self::Input) -> Answer {
+        let Filesystem { directories, files } = fs;
+        let mut sum = Size::default();
+        common::debug!(?directories);
+        for dir in directories {
+            let size = dir_size(dir, files);
+            common::trace!(dir, %size);
+            if size <= Size::from(100000u32) {
+                let total = CheckedAdd::checked_add(&sum, &size);
+                sum = checked(total, Self::DAY, || {
+                    format!("sum of sizes {} + {}", sum, size)
+                });
+            }
+        }
+        sum.into()
+    }
+
+    fn part2(fs: &Self::Input) -> Answer {
+        let Filesystem { directories, files } = fs;
+        let mut best_option = Size::from(UPDATE);
+        common::debug!(?directories);
+        let used = dir_size("/", files);
+        let free = CheckedSub::checked_sub(&Size::from(DISK), &used);
+        let free = checked(free, Self::DAY, || {
+            format!("free space {} - {}", DISK, used)
+        });
+        // Nothing to delete when there is enough room already
+        let required_mem = CheckedSub::checked_sub(&Size::from(UPDATE), &free).unwrap_or_default();
+        for dir in directories {
+            let size = dir_size(dir, files);
+            common::trace!(dir, %size);
+            if size >= required_mem && size < best_option {
+                common::trace!(dir, "best option");
+
+                best_option = size;
+            }
+        }
+        best_option.into()
+    }
+}
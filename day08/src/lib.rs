@@ -0,0 +1,63 @@
+use std::cmp::max;
+
+use common::{Answer, ParseError, Solution};
+use grid::{Grid, Pos, DIRECTIONS_4};
+
+pub struct Day08;
+
+/// Tree heights of the forest.
+pub type Forest = Grid<u8>;
+
+fn is_visible(pos: Pos, trees: &Forest) -> bool {
+    let current = trees.get(pos).unwrap();
+    DIRECTIONS_4
+        .iter()
+        .any(|&dir| trees.ray(pos, dir).all(|(_, h)| h < current))
+}
+
+fn scenic_score(pos: Pos, trees: &Forest) -> u32 {
+    let current = trees.get(pos).unwrap();
+    DIRECTIONS_4
+        .iter()
+        .map(|&dir| {
+            let mut score = 0;
+            for (_, h) in trees.ray(pos, dir) {
+                score += 1;
+                if h >= current {
+                    break;
+                }
+            }
+            score
+        })
+        .product()
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Forest;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Forest::parse(Self::DAY, input, "a tree height", |_, c| {
+            c.to_digit(10).map(|h| h as u8)
+        })
+    }
+
+    fn part1(trees: &Self::Input) -> Answer {
+        let mut visible: u32 = 0;
+        for pos in trees.bounds().positions() {
+            if is_visible(pos, trees) {
+                visible += 1;
+            }
+        }
+        visible.into()
+    }
+
+    fn part2(trees: &Self::Input) -> Answer {
+        let mut best_score = 0;
+        for pos in trees.bounds().positions() {
+            best_score = max(best_score, scenic_score(pos, trees))
+        }
+        best_score.into()
+    }
+}
@@ -0,0 +1,163 @@
+use regex::Regex;
+use std::collections::HashSet;
+
+use common::{lines, Answer, ParseError, Solution};
+use grid::SparseGrid;
+use visualize::{Frame, Frames, Visualize};
+
+pub struct Day09;
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+struct Rope {
+    head: (i32, i32),
+    knots: Vec<(i32, i32)>,
+}
+
+impl Rope {
+    fn new() -> Rope {
+        Rope {
+            head: (0, 0),
+            knots: vec![(0, 0)],
+        }
+    }
+
+    fn new_with_size(size: usize) -> Rope {
+        let mut r = Rope {
+            head: (0, 0),
+            knots: Vec::with_capacity(size - 1),
+        };
+        r.knots.resize(size - 1, (0, 0));
+        r
+    }
+
+    pub fn mv(&mut self, direction: &Direction) {
+        match direction {
+            Direction::Up => {
+                self.head.1 -= 1;
+            }
+            Direction::Down => {
+                self.head.1 += 1;
+            }
+            Direction::Left => {
+                self.head.0 -= 1;
+            }
+            Direction::Right => {
+                self.head.0 += 1;
+            }
+        }
+        self.move_knots();
+    }
+
+    fn move_knots(&mut self) {
+        for i in 0..self.knots.len() {
+            self.move_knot(i);
+        }
+    }
+
+    fn move_knot(&mut self, index: usize) {
+        let knot = self.knots[index];
+        let prev = match index {
+            0 => (self.head.0, self.head.1),
+            _ => self.knots[index - 1],
+        };
+        let adx = prev.0.abs_diff(knot.0);
+        let ady = prev.1.abs_diff(knot.1);
+        if ady == 2 || adx == 2 {
+            self.knots[index] = (
+                knot.0 + (prev.0 - knot.0).signum(),
+                knot.1 + (prev.1 - knot.1).signum(),
+            );
+        }
+    }
+
+    fn tail(&self) -> (i32, i32) {
+        *self.knots.last().unwrap()
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<(Direction, u32)>, ParseError> {
+    let re = Regex::new(r"^(U|D|L|R) (\d+)$").unwrap();
+    let mut motions = Vec::new();
+    for line in lines(Day09::DAY, input) {
+        let captures = re
+            .captures(line.text)
+            .ok_or_else(|| line.mismatch("a motion like `R 4`"))?;
+        let dir_str = captures.get(1).unwrap().as_str();
+        let steps: u32 = line.parse(captures.get(2).unwrap().as_str(), "a number of steps")?;
+        let dir = match dir_str {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => unreachable!(),
+        };
+        motions.push((dir, steps));
+    }
+    Ok(motions)
+}
+
+fn process(motions: &[(Direction, u32)], rope: &mut Rope) -> usize {
+    let mut positions = HashSet::new();
+    for (dir, steps) in motions {
+        for _i in 0..*steps {
+            rope.mv(dir);
+            positions.insert(rope.tail());
+        }
+    }
+    positions.len()
+}
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    /// Head motions: a direction and a number of steps.
+    type Input = Vec<(Direction, u32)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(motions: &Self::Input) -> Answer {
+        process(motions, &mut Rope::new()).into()
+    }
+
+    fn part2(motions: &Self::Input) -> Answer {
+        process(motions, &mut Rope::new_with_size(10)).into()
+    }
+}
+
+impl Visualize for Day09 {
+    /// A frame per motion: the rope over the positions visited by its tail.
+    fn visualize(motions: &Self::Input, part: u8, frame: &mut Frames) {
+        let mut rope = match part {
+            1 => Rope::new(),
+            _ => Rope::new_with_size(10),
+        };
+        let mut map = SparseGrid::new();
+        map.insert((0, 0), 's');
+        for (dir, steps) in motions {
+            for _i in 0..*steps {
+                rope.mv(dir);
+                if !map.contains(rope.tail()) {
+                    map.insert(rope.tail(), '#');
+                }
+            }
+            frame(&|| {
+                let mut rope_map = map.clone();
+                for (i, &knot) in rope.knots.iter().enumerate().rev() {
+                    rope_map.insert(knot, char::from_digit(i as u32 + 1, 10).unwrap());
+                }
+                rope_map.insert(rope.head, 'H');
+                Frame::from_grid(&rope_map, |_, c| *c.unwrap_or(&'.'))
+            });
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use regex::Regex;
+use std::collections::HashMap;
+
+use common::{lines, Answer, ParseError, Solution};
+
+pub struct Day10;
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+fn get_value_at(history: &HashMap<i32, i32>, index: i32) -> Option<i32> {
+    let mut i = index;
+    while i >= 0 {
+        match history.get(&i) {
+            Some(v) => {
+                return Some(*v);
+            }
+            None => {
+                i -= 1;
+            }
+        }
+    }
+    None
+}
+
+fn draw(clock: i32, x: i32) -> String {
+    let off = (clock - 1) % 40;
+    let mut c = String::from({
+        if x - 1 == off || x == off || x + 1 == off {
+            "#"
+        } else {
+            " "
+        }
+    });
+    if off == 39 {
+        c.push('\n');
+    }
+    c
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let re = Regex::new(r"^addx (.*)$").unwrap();
+        let mut program = Vec::new();
+        for line in lines(Self::DAY, input) {
+            match line.text {
+                "noop" => program.push(Instruction::Noop),
+                text => {
+                    let captures = re
+                        .captures(text)
+                        .ok_or_else(|| line.error_at(text, "`noop` or `addx`"))?;
+                    let dx = line.parse::<i32>(captures.get(1).unwrap().as_str(), "an integer")?;
+                    program.push(Instruction::Addx(dx));
+                }
+            }
+        }
+        Ok(program)
+    }
+
+    fn part1(program: &Self::Input) -> Answer {
+        let mut history = HashMap::new();
+        let mut clock = 1;
+        let mut x = 1;
+        history.insert(clock, x);
+        for instruction in program {
+            match instruction {
+                Instruction::Noop => {
+                    clock += 1;
+                }
+                Instruction::Addx(dx) => {
+                    clock += 2;
+                    x += dx;
+                    history.insert(clock, x);
+                }
+            }
+        }
+        let s: i32 = [20, 60, 100, 140, 180, 220]
+            .iter()
+            .map(|&x| get_value_at(&history, x).unwrap() * x)
+            .sum();
+        s.into()
+    }
+
+    fn part2(program: &Self::Input) -> Answer {
+        let mut clock = 1;
+        let mut x = 1;
+        let mut screen = String::new();
+        for instruction in program {
+            match instruction {
+                Instruction::Noop => {
+                    screen.push_str(draw(clock, x).as_str());
+                    clock += 1;
+                }
+                Instruction::Addx(dx) => {
+                    screen.push_str(draw(clock, x).as_str());
+                    clock += 1;
+                    screen.push_str(draw(clock, x).as_str());
+                    clock += 1;
+                    x += dx;
+                }
+            }
+        }
+        screen.into()
+    }
+}
@@ -0,0 +1,216 @@
+// Worry levels are only `Copy` without the `bigint` feature
+#![cfg_attr(not(feature = "bigint"), allow(clippy::clone_on_copy, clippy::op_ref))]
+
+use regex::Regex;
+use std::collections::HashMap;
+use std::collections::LinkedList;
+use std::vec::Vec;
+
+use common::overflow::checked;
+use common::{lines, Answer, Line, ParseError, Solution};
+use num_traits::{CheckedAdd, CheckedMul, Zero};
+
+pub struct Day11;
+
+#[cfg(not(feature = "bigint"))]
+type Worry = u64;
+#[cfg(feature = "bigint")]
+type Worry = num_bigint::BigUint;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+enum Operation {
+    Plus,
+    Multiply,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+enum Operand {
+    Old,
+    Int(u64),
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Monkey {
+    items: LinkedList<Worry>,
+    operation: (Operation, Operand, Operand),
+    test_value: u64,
+    test_true: usize,
+    test_false: usize,
+    business: u32,
+}
+
+impl Monkey {
+    fn new() -> Monkey {
+        Monkey {
+            items: LinkedList::new(),
+            operation: (Operation::Plus, Operand::Int(0), Operand::Int(0)),
+            test_value: 0,
+            test_true: 0,
+            test_false: 0,
+            business: 0,
+        }
+    }
+
+    fn set_operation(&mut self, line: &Line, op: &str) -> Result<(), ParseError> {
+        let re = Regex::new(r"^(old|\d+)\s+(\+|\*)\s+(old|\d+)$").unwrap(); // FIXME static
+        let capture = re
+            .captures(op)
+            .ok_or_else(|| line.error_at(op, "an operation like `old * 19`"))?;
+        let operand = |i| match capture.get(i).unwrap().as_str() {
+            "old" => Ok(Operand::Old),
+            v => line.parse::<u64>(v, "an integer").map(Operand::Int),
+        };
+        let left = operand(1)?;
+        let right = operand(3)?;
+        let op = match capture.get(2).unwrap().as_str() {
+            "+" => Operation::Plus,
+            "*" => Operation::Multiply,
+            _ => unreachable!(),
+        };
+        self.operation = (op, left, right);
+        Ok(())
+    }
+
+    fn exec_op(&mut self, old: Worry) -> Worry {
+        let operand = |operand: &Operand| match operand {
+            Operand::Old => old.clone(),
+            Operand::Int(v) => Worry::from(*v),
+        };
+        let left = operand(&self.operation.1);
+        let right = operand(&self.operation.2);
+
+        let (new, sign) = match self.operation.0 {
+            Operation::Plus => (CheckedAdd::checked_add(&left, &right), '+'),
+            Operation::Multiply => (CheckedMul::checked_mul(&left, &right), '*'),
+        };
+        checked(new, Day11::DAY, || {
+            format!("worry level {} {} {}", left, sign, right)
+        })
+    }
+
+    pub fn inspect(&mut self, worry: impl Fn(Worry) -> Worry) -> HashMap<usize, Vec<Worry>> {
+        // Both tests may throw to the same monkey
+        let mut map: HashMap<usize, Vec<Worry>> = HashMap::new();
+        while !self.items.is_empty() {
+            let item = self.items.pop_front().unwrap();
+            let new = worry(self.exec_op(item));
+            let target = if (&new % Worry::from(self.test_value)).is_zero() {
+                self.test_true
+            } else {
+                self.test_false
+            };
+            map.entry(target).or_default().push(new);
+            self.business += 1;
+        }
+        map
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut monkeys = Vec::new();
+    let mut targets = Vec::new();
+    for line in lines(Day11::DAY, input) {
+        let text = line.text;
+        if text.starts_with("Monkey") {
+            monkeys.push(Monkey::new());
+            continue;
+        }
+        if text.is_empty() {
+            continue;
+        }
+        let current = monkeys
+            .last_mut()
+            .ok_or_else(|| line.mismatch("a `Monkey N:` header"))?;
+        if let Some(items) = text.strip_prefix("  Starting items: ") {
+            for item in items.split(", ").filter(|i| !i.is_empty()) {
+                current.items.push_back(line.parse(item, "a worry level")?);
+            }
+        } else if let Some(op) = text.strip_prefix("  Operation: new = ") {
+            //current.operation = tmp.to_string();
+            current.set_operation(&line, op)?;
+        } else if let Some(v) = text.strip_prefix("  Test: divisible by ") {
+            current.test_value = line.parse(v, "a divisor")?;
+            if current.test_value == 0 {
+                return Err(line.error_at(v, "a non-zero divisor"));
+            }
+        } else if let Some(v) = text.strip_prefix("    If true: throw to monkey ") {
+            current.test_true = line.parse(v, "a monkey number")?;
+            targets.push((current.test_true, line, v));
+        } else if let Some(v) = text.strip_prefix("    If false: throw to monkey ") {
+            current.test_false = line.parse(v, "a monkey number")?;
+            targets.push((current.test_false, line, v));
+        } else {
+            return Err(line.mismatch("a monkey attribute"));
+        }
+        common::trace!(monkey = ?current);
+    }
+    if let Some((_, line, v)) = targets.iter().find(|(t, _, _)| *t >= monkeys.len()) {
+        return Err(line.error_at(v, format!("a monkey between 0 and {}", monkeys.len() - 1)));
+    }
+    Ok(monkeys)
+}
+
+/// Monkey business after `rounds` rounds without relief. Worry levels are kept modulo the
+/// product of the divisors, which leaves every divisibility test unchanged.
+pub fn anxious_business(monkeys: &[Monkey], rounds: u32) -> u64 {
+    let mut monkeys = monkeys.to_vec();
+    let mut total_product = Worry::from(1u64);
+    for monkey in &monkeys {
+        let divisor = Worry::from(monkey.test_value);
+        let product = CheckedMul::checked_mul(&total_product, &divisor);
+        total_product = checked(product, Day11::DAY, || {
+            format!("product of the divisors {} * {}", total_product, divisor)
+        });
+    }
+    process(&mut monkeys, rounds, |item| item % &total_product)
+}
+
+fn process(monkeys: &mut Vec<Monkey>, iter: u32, worry: impl Fn(Worry) -> Worry) -> u64 {
+    for round in 1..=iter {
+        let _round = common::span!("round", round);
+        for index in 0..monkeys.len() {
+            let monkey = monkeys.get_mut(index).unwrap();
+            let updates = monkey.inspect(&worry);
+            common::trace!(monkey = index, ?updates);
+            for (k, items) in updates {
+                for item in items {
+                    monkeys.get_mut(k).unwrap().items.push_back(item);
+                }
+            }
+        }
+    }
+    let mut most_active = 0;
+    let mut second_most_active = 0;
+    for monkey in monkeys {
+        if monkey.business >= most_active {
+            second_most_active = most_active;
+            most_active = monkey.business;
+        } else if monkey.business >= second_most_active {
+            second_most_active = monkey.business;
+        }
+    }
+    common::debug!(most_active, second_most_active);
+    most_active as u64 * second_most_active as u64
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(monkeys: &Self::Input) -> Answer {
+        let mut monkeys = monkeys.clone();
+        process(&mut monkeys, 20, |item| item / Worry::from(3u64)).into()
+    }
+
+    fn part2(monkeys: &Self::Input) -> Answer {
+        anxious_business(monkeys, 10000).into()
+    }
+}
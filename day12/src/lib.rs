@@ -0,0 +1,126 @@
+use grid::{Grid, Pos};
+use search::bfs;
+
+use common::{lines, Answer, Assumption, ParseError, Solution};
+
+pub struct Day12;
+
+pub type Terrain = Grid<u8>;
+
+/// The terrain with the start and goal squares.
+pub type Map = (Terrain, Pos, Pos);
+
+fn parse(input: &str) -> Result<Map, ParseError> {
+    let mut start = None;
+    let mut end = None;
+    let terrain = Terrain::parse(Day12::DAY, input, "an elevation", |pos, c| match c {
+        'S' => {
+            start = Some(pos);
+            Some(0)
+        }
+        'E' => {
+            end = Some(pos);
+            Some(25)
+        }
+        'a'..='z' => Some(c as u8 - b'a'),
+        _ => None,
+    })?;
+    let start = start.ok_or_else(|| ParseError::end_of_input(Day12::DAY, input, "a start `S`"))?;
+    let end = end.ok_or_else(|| ParseError::end_of_input(Day12::DAY, input, "a goal `E`"))?;
+    Ok((terrain, start, end))
+}
+
+/// The error of a goal `E` that cannot be reached `from` where the climb starts.
+fn unreachable((x, y): Pos, from: &str) -> ParseError {
+    ParseError {
+        day: Day12::DAY,
+        line_no: y as usize + 1,
+        column: x as usize + 1,
+        expected: format!("a goal `E` reachable from {}", from),
+        found: String::from("no path"),
+    }
+}
+
+/// Squares that can be climbed to from `pos`, at most one higher.
+fn climbs(terrain: &Terrain, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+    let this = terrain[pos];
+    terrain
+        .neighbours_4(pos)
+        .filter(move |&next| terrain[next] <= this + 1)
+}
+
+/// Squares that can be climbed from to reach `pos`, at most one lower.
+fn descents(terrain: &Terrain, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+    let this = terrain[pos];
+    terrain
+        .neighbours_4(pos)
+        .filter(move |&next| terrain[next] + 1 >= this)
+}
+
+/// Errors for every `marker` past the first one, or at the end of the input if there is none.
+fn exactly_one(input: &str, marker: char, name: &str) -> Vec<ParseError> {
+    let mut found = lines(Day12::DAY, input).flat_map(|line| {
+        line.text
+            .char_indices()
+            .filter(move |&(_, c)| c == marker)
+            .map(move |(i, _)| (line, i))
+    });
+    if found.next().is_none() {
+        return vec![ParseError::end_of_input(Day12::DAY, input, name)];
+    }
+    found
+        .map(|(line, i)| {
+            let column = line.column_of(&line.text[i..]);
+            line.error(
+                column,
+                format!("a single {}", name),
+                format!("another `{}`", marker),
+            )
+        })
+        .collect()
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let &(ref terrain, start, end) = input;
+        common::debug!(
+            width = terrain.width(),
+            height = terrain.height(),
+            ?start,
+            ?end
+        );
+        let path = bfs(start, |&pos| climbs(terrain, pos), |&pos| pos == end);
+        let path = path.unwrap_or_else(|| common::reject(unreachable(end, "the start `S`")));
+        (path.cost as u32).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let &(ref terrain, _, end) = input;
+        common::debug!(width = terrain.width(), height = terrain.height(), ?end);
+
+        let path = bfs(end, |&pos| descents(terrain, pos), |&pos| terrain[pos] == 0);
+        let path = path.unwrap_or_else(|| common::reject(unreachable(end, "any square `a`")));
+        (path.cost as u32).into()
+    }
+
+    fn check(input: &str) -> Vec<Assumption> {
+        vec![
+            Assumption::new(
+                "there is exactly one start `S`",
+                exactly_one(input, 'S', "start `S`"),
+            ),
+            Assumption::new(
+                "there is exactly one goal `E`",
+                exactly_one(input, 'E', "goal `E`"),
+            ),
+        ]
+    }
+}
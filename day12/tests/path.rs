@@ -0,0 +1,18 @@
+use common::SolveError;
+use day12::Day12;
+
+#[test]
+fn unreachable_goal() {
+    for part in [1, 2] {
+        match common::solve::<Day12>("Sb\nzE\nyx\n", &[part]) {
+            Err(SolveError::Parse(err)) => {
+                assert_eq!((err.line_no, err.column), (2, 2));
+                assert_eq!(err.found, "no path");
+            }
+            other => panic!("expected no path, got {:?}", other),
+        }
+    }
+    let answers = common::solve::<Day12>("SbcdefghijklmnopqrstuvwxyE\n", &[1, 2]).unwrap();
+    assert_eq!(answers[0].answer.to_string(), "25");
+    assert_eq!(answers[1].answer.to_string(), "25");
+}
@@ -0,0 +1,189 @@
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete;
+use nom::multi::separated_list0;
+use nom::IResult;
+use std::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
+
+use common::{lines, Answer, Line, ParseError, Solution};
+
+pub struct Day13;
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+pub enum Packet {
+    Int(u32),
+    List(Vec<Packet>),
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Packet {}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match compare(self, other) {
+            0 => Ordering::Equal,
+            1 => Ordering::Less,
+            -1 => Ordering::Greater,
+            _ => {
+                panic!("WAT")
+            }
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn parse_number(input: &str) -> IResult<&str, Packet> {
+    let (input, v) = complete::u32(input)?;
+    Ok((input, Packet::Int(v)))
+}
+
+fn parse_packet(input: &str) -> IResult<&str, Packet> {
+    let (input, _) = tag("[")(input)?;
+    let (input, vec) = separated_list0(tag(","), parse_line)(input)?;
+    let (input, _) = tag("]")(input)?;
+    Ok((input, Packet::List(vec)))
+}
+
+fn parse_line(input: &str) -> IResult<&str, Packet> {
+    let (input, p) = alt((parse_number, parse_packet))(input)?;
+    Ok((input, p))
+}
+
+fn compare(p1: &Packet, p2: &Packet) -> i32 {
+    common::trace!(?p1, ?p2, "compare");
+    match (p1, p2) {
+        (Packet::Int(x), Packet::Int(y)) => {
+            if x < y {
+                1
+            } else if x > y {
+                -1
+            } else {
+                0
+            }
+        }
+        (Packet::List(v1), Packet::List(v2)) => {
+            let mut index = 0;
+            loop {
+                if index == v1.len() && index < v2.len() {
+                    return 1;
+                } else if index < v1.len() && index == v2.len() {
+                    return -1;
+                } else if index == v1.len() && index == v2.len() {
+                    return 0;
+                } else {
+                    let p1 = &v1[index];
+                    let p2 = &v2[index];
+                    let cmp = compare(p1, p2);
+                    if cmp != 0 {
+                        return cmp;
+                    }
+                    index += 1;
+                }
+            }
+        }
+        (Packet::Int(x), p) => {
+            let p1 = Box::new(Packet::List(vec![Packet::Int(*x)]));
+            let p2 = Box::new(p);
+            compare(&p1, &p2)
+        }
+        (p, Packet::Int(x)) => {
+            let p1 = Box::new(p);
+            let p2 = Box::new(Packet::List(vec![Packet::Int(*x)]));
+            compare(&p1, &p2)
+        }
+    }
+}
+
+/// Deeper lists would overflow the stack of the recursive parser and comparison.
+const MAX_DEPTH: usize = 100;
+
+fn parse_packet_line(line: &Line) -> Result<Packet, ParseError> {
+    let mut depth = 0usize;
+    for (i, c) in line.text.char_indices() {
+        match c {
+            '[' if depth == MAX_DEPTH => {
+                let expected = format!("lists nested at most {} deep", MAX_DEPTH);
+                return Err(line.error(line.column_of(&line.text[i..]), expected, "`[`"));
+            }
+            '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+    match parse_line(line.text) {
+        Ok(("", packet)) => Ok(packet),
+        Ok((rest, _)) => Err(line.error_at(rest, "the end of the packet")),
+        Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => {
+            Err(line.error_at(e.input, "a packet"))
+        }
+        Err(nom::Err::Incomplete(_)) => unreachable!(),
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<(Packet, Packet)>, ParseError> {
+    let mut lines = lines(Day13::DAY, input);
+    let mut pairs = Vec::new();
+    while let Some(l1) = lines.next() {
+        let l2 = lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(Day13::DAY, input, "a second packet"))?;
+
+        let p1 = parse_packet_line(&l1)?;
+        let p2 = parse_packet_line(&l2)?;
+        common::trace!(?p1, ?p2);
+        pairs.push((p1, p2));
+
+        // read empty line
+        if let Some(blank) = lines.next().filter(|l| !l.text.is_empty()) {
+            return Err(blank.mismatch("an empty line"));
+        }
+    }
+    Ok(pairs)
+}
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<(Packet, Packet)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let mut sum = 0u32;
+        for (index, (p1, p2)) in input.iter().enumerate() {
+            if p1 < p2 {
+                sum += index as u32 + 1;
+            }
+        }
+        sum.into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (_, marker1) = parse_line("[[2]]").ok().unwrap();
+        let (_, marker2) = parse_line("[[6]]").ok().unwrap();
+        let mut packets: Vec<&Packet> = input.iter().flat_map(|(p1, p2)| [p1, p2]).collect();
+        packets.push(&marker1);
+        packets.push(&marker2);
+        packets.sort();
+        let mut prod = 1u32;
+        for (index, &p) in packets.iter().enumerate() {
+            if p == &marker1 || p == &marker2 {
+                prod *= index as u32 + 1;
+            }
+        }
+        prod.into()
+    }
+}
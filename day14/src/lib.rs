@@ -0,0 +1,203 @@
+use nom::multi::separated_list1;
+use nom::{bytes::complete::tag, character::complete, IResult};
+use std::fmt::{self, Display, Formatter};
+
+use common::{lines, Answer, ParseError, Solution};
+use grid::{add, Pos, SparseGrid, DOWN};
+use visualize::{Frame, Frames, Visualize};
+
+pub struct Day14;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+enum TileType {
+    Rock,
+    Sand,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Cave {
+    tiles: SparseGrid<TileType>,
+    sand_cound: u32,
+    current_pos: Pos,
+    max_y: i32,
+}
+
+impl Cave {
+    fn new() -> Cave {
+        Cave {
+            tiles: SparseGrid::new(),
+            sand_cound: 1,
+            current_pos: (500, 0),
+            max_y: 0,
+        }
+    }
+
+    fn add_rocks(&mut self, points: &[Pos]) {
+        if points.is_empty() {
+            return;
+        }
+        let mut origin = points[0];
+        self.tiles.insert(origin, TileType::Rock);
+        for p in &points[1..] {
+            if origin.0 == p.0 {
+                let x = p.0;
+                let start = i32::min(origin.1, p.1);
+                let end = i32::max(origin.1, p.1);
+                for y in start..=end {
+                    self.tiles.insert((x, y), TileType::Rock);
+                }
+                origin = *p;
+            } else if origin.1 == p.1 {
+                let y = p.1;
+                let start = i32::min(origin.0, p.0);
+                let end = i32::max(origin.0, p.0);
+                for x in start..=end {
+                    self.tiles.insert((x, y), TileType::Rock);
+                }
+                origin = *p;
+            } else {
+                panic!("Invalid line {:?}-{:?}", origin, p);
+            }
+        }
+        self.max_y = self.tiles.bounds().max.1;
+    }
+
+    fn step(&mut self) {
+        let next_pos_1 = add(self.current_pos, DOWN); // x, y+1
+        let next_pos_2 = add(self.current_pos, (-1, 1)); // x-1, y+1
+        let next_pos_3 = add(self.current_pos, (1, 1)); // x+1, y+1
+        if !self.tiles.contains(next_pos_1) {
+            self.current_pos = next_pos_1;
+        } else if !self.tiles.contains(next_pos_2) {
+            self.current_pos = next_pos_2;
+        } else if !self.tiles.contains(next_pos_3) {
+            self.current_pos = next_pos_3;
+        } else {
+            self.tiles.insert(self.current_pos, TileType::Sand);
+            self.current_pos = (500, 0);
+            self.sand_cound += 1;
+        }
+    }
+
+    fn step_2(&mut self) {
+        if self.current_pos.1 == self.max_y + 1 {
+            self.tiles.insert(self.current_pos, TileType::Sand);
+            self.current_pos = (500, 0);
+            self.sand_cound += 1;
+        } else {
+            self.step();
+        }
+    }
+}
+
+impl Display for Cave {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let map = self.tiles.render(|p, tile| match tile {
+            _ if p == self.current_pos => '+',
+            None => '.',
+            Some(TileType::Rock) => '#',
+            Some(TileType::Sand) => 'o',
+        });
+        write!(f, "{}", map)
+    }
+}
+
+fn parse_point(input: &str) -> IResult<&str, Pos> {
+    let (input, x) = complete::i32(input)?;
+    let (input, _) = tag(",")(input)?;
+    let (input, y) = complete::i32(input)?;
+    Ok((input, (x, y)))
+}
+
+fn parse_line(input: &str) -> IResult<&str, Vec<Pos>> {
+    let (input, vec) = separated_list1(tag(" -> "), parse_point)(input)?;
+    Ok((input, vec))
+}
+
+/// Largest coordinate of the rocks, `x` on either side of 0: the cave is a few hundred cells
+/// wide and deep.
+const LIMIT: i32 = 1000;
+
+fn parse(input: &str) -> Result<Cave, ParseError> {
+    let mut cave = Cave::new();
+    for line in lines(Day14::DAY, input) {
+        let v = match parse_line(line.text) {
+            Ok(("", v)) => v,
+            Ok((rest, _)) => return Err(line.error_at(rest, "` -> ` or the end of the path")),
+            Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => {
+                return Err(line.error_at(e.input, "a point like `498,4`"))
+            }
+            Err(nom::Err::Incomplete(_)) => unreachable!(),
+        };
+        if let Some(i) = v
+            .iter()
+            .position(|&(x, y)| x.abs() > LIMIT || !(0..=LIMIT).contains(&y))
+        {
+            let point = line.text.split(" -> ").nth(i).unwrap();
+            return Err(line.error_at(point, format!("a point between -{0},0 and {0},{0}", LIMIT)));
+        }
+        if let Some(i) = (1..v.len()).find(|&i| v[i].0 != v[i - 1].0 && v[i].1 != v[i - 1].1) {
+            let point = line.text.split(" -> ").nth(i).unwrap();
+            return Err(line.error_at(point, "a point in line with the previous one"));
+        }
+        cave.add_rocks(v.as_slice());
+    }
+    Ok(cave)
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Cave;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let mut cave = input.clone();
+        for _ in 0.. {
+            cave.step();
+            common::trace!(pos = ?cave.current_pos, sand = cave.sand_cound);
+            if cave.current_pos.1 > cave.max_y {
+                return (cave.sand_cound - 1).into();
+            }
+        }
+        0u32.into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let mut cave = input.clone();
+        for _ in 0.. {
+            cave.step_2();
+            common::trace!(pos = ?cave.current_pos, sand = cave.sand_cound);
+
+            if cave.tiles.contains((500, 0)) {
+                return (cave.sand_cound - 1).into();
+            }
+        }
+        0u32.into()
+    }
+}
+
+impl Visualize for Day14 {
+    /// A frame per unit of sand coming to rest.
+    fn visualize(input: &Self::Input, part: u8, frame: &mut Frames) {
+        let mut cave = input.clone();
+        loop {
+            let count = cave.sand_cound;
+            match part {
+                1 => cave.step(),
+                _ => cave.step_2(),
+            }
+            if cave.sand_cound != count {
+                frame(&|| Frame::new(cave.tiles.bounds().min, &cave.to_string()));
+            }
+            if (part == 1 && cave.current_pos.1 > cave.max_y) || cave.tiles.contains((500, 0)) {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use common::Solution;
+use day14::Day14;
+
+#[test]
+fn negative_x() {
+    let cave = Day14::parse("-5,4 -> -5,6 -> -7,6\n").unwrap();
+    assert_eq!(Day14::part1(&cave).to_string(), "0");
+    assert_eq!(Day14::part2(&cave).to_string(), "64");
+}
+
+#[test]
+fn out_of_bounds() {
+    for (input, column) in [
+        ("-1001,4 -> -5,4", 1),
+        ("5,4 -> 5,-4", 8),
+        ("0,1 -> 0,1001", 8),
+    ] {
+        let err = Day14::parse(input).unwrap_err();
+        assert_eq!(err.column, column, "{}", input);
+        assert_eq!(err.expected, "a point between -1000,0 and 1000,1000");
+    }
+}
@@ -0,0 +1,153 @@
+use regex::Regex;
+use std::collections::HashSet;
+
+use common::{lines, Answer, Assumption, ParseError, Solution};
+
+pub struct Day15;
+
+/// Sensors and beacons, with the row to scan for part 1.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Report {
+    sensors: Vec<Sensor>,
+    beacons: HashSet<(i64, i64)>,
+    target: i64,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+struct Sensor {
+    position: (i64, i64),
+    radius: i64,
+}
+
+fn parse(input: &str) -> Result<Report, ParseError> {
+    let mut sensors = Vec::<Sensor>::new();
+    let mut beacons = HashSet::<(i64, i64)>::new();
+    let re = Regex::new(
+        r"^Sensor at x=([-]?\d+), y=([-]?\d+): closest beacon is at x=([-]?\d+), y=([-]?\d+)$",
+    )
+    .unwrap();
+    let mut lines = lines(Day15::DAY, input);
+    let first = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(Day15::DAY, input, "the target row"))?;
+    let target = first.parse::<i32>(first.text, "the target row")? as i64;
+    for line in lines {
+        let captures = re.captures(line.text).ok_or_else(|| {
+            line.mismatch("a report like `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`")
+        })?;
+        let coordinate = |i| {
+            line.parse::<i32>(captures.get(i).unwrap().as_str(), "a coordinate")
+                .map(i64::from)
+        };
+        let sensor = (coordinate(1)?, coordinate(2)?);
+        let beacon = (coordinate(3)?, coordinate(4)?);
+        let s = Sensor {
+            position: sensor,
+            radius: (sensor.0.abs_diff(beacon.0) + sensor.1.abs_diff(beacon.1)) as i64,
+        };
+        sensors.push(s);
+        beacons.insert(beacon);
+    }
+    Ok(Report {
+        sensors,
+        beacons,
+        target,
+    })
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Report;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let Report {
+            sensors,
+            beacons,
+            target,
+        } = input;
+        let target = *target;
+        let mut interval_list = Vec::<(i64, i64)>::new();
+        for s in sensors {
+            let distance = s.radius;
+            if target.abs_diff(s.position.1) as i64 <= distance {
+                let diff = distance - target.abs_diff(s.position.1) as i64;
+                let interval = (s.position.0 - diff, s.position.0 + diff);
+                interval_list.push(interval);
+            }
+        }
+        let min = interval_list.iter().map(|&(xmin, _)| xmin).min().unwrap();
+        let max = interval_list.iter().map(|&(_, xmax)| xmax).max().unwrap();
+        let mut count = 0u32;
+        for x in min..=max {
+            for i in &interval_list {
+                if x >= i.0 && x <= i.1 {
+                    count += 1;
+                    break;
+                }
+            }
+        }
+        for beacon in beacons {
+            if beacon.1 == target {
+                count -= 1;
+            }
+        }
+        count.into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        match find_beacon(input, 4000000) {
+            Some((x, y)) => (x * 4000000 + y).into(),
+            None => (-1i64).into(),
+        }
+    }
+
+    fn check(input: &str) -> Vec<Assumption> {
+        let violations = match lines(Self::DAY, input).next() {
+            Some(line) => line
+                .parse::<i32>(line.text, "the target row, like `2000000`")
+                .err()
+                .into_iter()
+                .collect(),
+            None => vec![ParseError::end_of_input(Self::DAY, input, "the target row")],
+        };
+        vec![Assumption::new(
+            "the first line is the row to scan in part 1",
+            violations,
+        )]
+    }
+}
+
+/// First position, row by row, of the square from `(0, 0)` to `(size, size)` that no sensor
+/// covers. Each row is scanned skipping at once past the sensors covering the current position.
+pub fn find_beacon(report: &Report, size: i64) -> Option<(i64, i64)> {
+    let sensors = &report.sensors;
+    let mut y = 0i64;
+    let mut x = 0i64;
+    while y <= size {
+        let _row = common::span!("row", y);
+        'out: while x <= size {
+            for s in sensors {
+                let dy = s.position.1.abs_diff(y) as i64;
+                let dx = s.position.0.abs_diff(x) as i64;
+                if dx + dy <= s.radius {
+                    x = s.position.0 + s.radius - dy + 1;
+                    common::trace!(x, "skip past sensor");
+                    continue 'out;
+                }
+            }
+            common::debug!(x, y, "distress beacon");
+
+            return Some((x, y));
+        }
+        y += 1;
+        x = 0;
+    }
+    None
+}
@@ -0,0 +1,213 @@
+use regex::Regex;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use common::{lines, Answer, Assumption, ParseError, Solution};
+use search::bfs;
+
+pub struct Day16;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Valve {
+    id: String,
+    rate: i32,
+    links: Vec<String>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Room {
+    rate: i32,
+    paths: HashMap<String, i32>,
+}
+
+fn parse(input: &str) -> Result<HashMap<String, Valve>, ParseError> {
+    let re = Regex::new(
+        r"^Valve ([A-Z][A-Z]) has flow rate=(\d+); tunnel[s]? lead[s]? to valve[s]? (.+)$",
+    )
+    .unwrap();
+    let mut valves = HashMap::<String, Valve>::new();
+    let mut links = Vec::new();
+    for line in lines(Day16::DAY, input) {
+        let captures = re.captures(line.text).ok_or_else(|| {
+            line.mismatch("a valve like `Valve BB has flow rate=13; tunnels lead to valves CC, AA`")
+        })?;
+        let id = captures.get(1).unwrap();
+        if valves.contains_key(id.as_str()) {
+            return Err(line.error_at(id.as_str(), "a new valve"));
+        }
+        let rate = line.parse::<i32>(captures.get(2).unwrap().as_str(), "a flow rate")?;
+        for link in captures.get(3).unwrap().as_str().split(", ") {
+            if link.len() != 2 || !link.chars().all(|c| c.is_ascii_uppercase()) {
+                return Err(line.error_at(link, "a valve name"));
+            }
+            links.push((line, link));
+        }
+        let valve = Valve {
+            id: id.as_str().to_string(),
+            rate,
+            links: captures[3].split(", ").map(|s| s.to_string()).collect(),
+        };
+        valves.insert(valve.id.clone(), valve);
+    }
+    if let Some((line, link)) = links.iter().find(|(_, link)| !valves.contains_key(*link)) {
+        return Err(line.error_at(link, "a known valve"));
+    }
+    if !valves.contains_key("AA") {
+        return Err(ParseError::end_of_input(Day16::DAY, input, "a valve `AA`"));
+    }
+    Ok(valves)
+}
+
+fn compute_path(start: &str, end: &str, valves: &HashMap<String, Valve>) -> i32 {
+    let path = bfs(
+        start,
+        |&current| valves[current].links.iter().map(String::as_str),
+        |&current| current == end,
+    );
+    path.map_or(0, |path| path.cost as i32)
+}
+
+fn walk<'a>(
+    current: &'a str,
+    remaining_rooms: &HashSet<&'a str>,
+    remaining_time: i32,
+    rooms: &HashMap<String, Room>,
+) -> i32 {
+    if remaining_time <= 0 {
+        return 0;
+    }
+
+    let mut new_remaining_rooms = remaining_rooms.clone();
+    new_remaining_rooms.remove(current);
+
+    //let new_flow = flow + remaining_time * rooms[room].rate;
+
+    let mut best_flow = 0;
+    let rate = rooms[current].rate;
+    let flow = remaining_time * rate;
+    common::trace!(current, flow, remaining_time);
+    for (room, len) in &rooms[current].paths {
+        if !new_remaining_rooms.contains(&room.as_str()) {
+            continue;
+        }
+        let remaining_flow = walk(room, &new_remaining_rooms, remaining_time - len - 1, rooms);
+        best_flow = best_flow.max(remaining_flow);
+    }
+    flow + best_flow
+}
+
+fn make_room_graph(valves: &HashMap<String, Valve>) -> HashMap<String, Room> {
+    let mut working_valves: Vec<String> = valves
+        .iter()
+        .filter(|&(_, v)| v.rate > 0 || v.id == "AA")
+        .map(|(k, _)| k.clone())
+        .collect::<Vec<String>>();
+    working_valves.sort();
+    let mut rooms = HashMap::new();
+    for from in &working_valves {
+        let mut room = Room {
+            rate: valves[from].rate,
+            paths: HashMap::new(),
+        };
+        for to in &working_valves {
+            if from == to {
+                continue;
+            }
+            let r = compute_path(from, to, valves);
+            room.paths.insert(to.clone(), r);
+        }
+        rooms.insert(from.clone(), room);
+    }
+    common::debug!(?rooms);
+    rooms
+}
+
+fn subsets<T>(s: &[T]) -> Vec<Vec<T>>
+where
+    T: Clone,
+{
+    (0..2usize.pow(s.len() as u32))
+        .map(|i| {
+            s.iter()
+                .enumerate()
+                .filter(|&(t, _)| (i >> t) % 2 == 1)
+                .map(|(_, element)| element.clone())
+                .collect()
+        })
+        .collect()
+}
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = HashMap<String, Room>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(make_room_graph(&parse(input)?))
+    }
+
+    fn part1(rooms: &Self::Input) -> Answer {
+        let remaining_rooms = rooms.keys().map(|s| s.as_str()).collect::<HashSet<&str>>();
+        walk("AA", &remaining_rooms, 30, rooms).into()
+    }
+
+    fn part2(rooms: &Self::Input) -> Answer {
+        let all_rooms = subsets(&rooms.keys().map(|s| s.as_str()).collect::<Vec<&str>>());
+        common::debug!(subsets = all_rooms.len());
+        let mut best_stream = 0;
+        for set in &all_rooms {
+            common::cancel::checkpoint();
+            let remaining_rooms = set.iter().cloned().collect::<HashSet<&str>>();
+            let elephant_rooms = rooms
+                .keys()
+                .filter_map(|s| {
+                    if set.contains(&s.as_str()) {
+                        None
+                    } else {
+                        Some(s.as_str())
+                    }
+                })
+                .collect::<HashSet<&str>>();
+            let _split = common::span!("split", ?remaining_rooms, ?elephant_rooms);
+
+            best_stream = best_stream.max(
+                walk("AA", &remaining_rooms, 26, rooms) + walk("AA", &elephant_rooms, 26, rooms),
+            );
+        }
+        best_stream.into()
+    }
+
+    fn check(input: &str) -> Vec<Assumption> {
+        let mut ids = Vec::new();
+        let mut start = vec![ParseError::end_of_input(Self::DAY, input, "a valve `AA`")];
+        for line in lines(Self::DAY, input) {
+            if let Some(id) = line.text.strip_prefix("Valve ") {
+                let id = id.split(' ').next().unwrap_or("");
+                if id == "AA" {
+                    start.clear();
+                }
+                ids.push((line, id));
+            }
+            if let Some((_, links)) = line.text.split_once(" to valve") {
+                let links = links.trim_start_matches('s').trim_start();
+                ids.extend(links.split(", ").map(|id| (line, id)));
+            }
+        }
+        let names = ids
+            .into_iter()
+            .filter(|(_, id)| id.len() != 2 || !id.chars().all(|c| c.is_ascii_uppercase()))
+            .map(|(line, id)| {
+                line.error(
+                    line.column_of(id),
+                    "a two-letter valve ID",
+                    format!("`{}`", id),
+                )
+            })
+            .collect();
+        vec![
+            Assumption::new("valves are named with two capital letters", names),
+            Assumption::new("there is a valve `AA` to start from", start),
+        ]
+    }
+}
@@ -0,0 +1,290 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Display, Formatter};
+
+use common::{lines, Answer, ParseError, Solution};
+use grid::{add, Pos, SparseGrid, LEFT, RIGHT};
+use visualize::{Frame, Frames, Visualize};
+
+/// The tower grows upwards, `y` increasing with the height.
+const BELOW: Pos = (0, -1);
+
+pub struct Day17;
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum RockType {
+    Minus,
+    Plus,
+    Angle,
+    Stick,
+    Stone,
+}
+
+#[derive(Debug)]
+struct Rock {
+    typ: RockType,
+    origin: Pos,
+}
+
+impl Rock {
+    fn new(typ: RockType, origin: Pos) -> Rock {
+        Rock { typ, origin }
+    }
+
+    fn points(&self) -> Vec<Pos> {
+        let (x, y) = self.origin;
+        match self.typ {
+            RockType::Minus => vec![(x, y), (x + 1, y), (x + 2, y), (x + 3, y)],
+            RockType::Plus => vec![
+                (x, y + 1),
+                (x + 1, y),
+                (x + 1, y + 1),
+                (x + 1, y + 2),
+                (x + 2, y + 1),
+            ],
+            RockType::Angle => vec![
+                (x, y),
+                (x + 1, y),
+                (x + 2, y),
+                (x + 2, y + 1),
+                (x + 2, y + 2),
+            ],
+            RockType::Stick => vec![(x, y), (x, y + 1), (x, y + 2), (x, y + 3)],
+            RockType::Stone => vec![(x, y), (x, y + 1), (x + 1, y), (x + 1, y + 1)],
+        }
+    }
+
+    /// The rock moved by `direction` hits the floor, a wall or a resting rock.
+    fn collides(&self, direction: Pos, rocks: &SparseGrid<()>) -> bool {
+        self.points()
+            .into_iter()
+            .map(|p| add(p, direction))
+            .any(|(x, y)| !(0..=6).contains(&x) || y < 1 || rocks.contains((x, y)))
+    }
+
+    fn shift(&mut self, direction: Pos) {
+        self.origin = add(self.origin, direction);
+    }
+}
+
+/// What decides how the next rocks fall. The top of the tower is the set of empty cells the
+/// rocks can reach from above, `None` when it goes too deep to be worth comparing.
+#[derive(Debug, Hash, PartialEq, Eq)]
+struct State {
+    clock: u32,
+    top: Option<Vec<Pos>>,
+    next_rock_type: RockType,
+}
+
+#[derive(Debug)]
+struct Cave {
+    clock: u32,
+    next_rock_type: RockType,
+    rocks: SparseGrid<()>,
+    jet: String,
+}
+
+impl Display for Cave {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let max = self.max_height();
+        for y in (1..=max + 1).rev() {
+            write!(f, "|")?;
+            for x in 0..=6 {
+                let p = self.rocks.get((x, y)).map(|_| "#").unwrap_or(".");
+                write!(f, "{}", p)?;
+            }
+            writeln!(f, "|")?;
+        }
+        write!(f, "+-------+")
+    }
+}
+
+impl Cave {
+    fn new(jet: &str) -> Cave {
+        Cave {
+            clock: 0,
+            next_rock_type: RockType::Minus,
+            rocks: SparseGrid::new(),
+            jet: String::from(jet),
+        }
+    }
+
+    fn max_height(&self) -> i32 {
+        self.rocks.bounds().max.1.max(0)
+    }
+
+    /// The `rows` highest rows of the tower and above, with the floor once in sight.
+    fn top(&self, rows: i32) -> String {
+        let max = self.max_height();
+        let mut out = String::new();
+        for y in (max + 4 - rows..=max + 3).rev() {
+            match y {
+                0 => out.push_str("+-------+"),
+                y if y < 0 => {}
+                y => {
+                    out.push('|');
+                    out.extend((0..=6).map(|x| {
+                        if self.rocks.contains((x, y)) {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    }));
+                    out.push('|');
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Empty cells reachable from the row above the tower, relative to its height, or `None`
+    /// if they go more than `depth` rows down.
+    fn reachable(&self, depth: i32) -> Option<Vec<Pos>> {
+        let max = self.max_height();
+        let mut seen: HashSet<Pos> = (0..=6).map(|x| (x, max + 1)).collect();
+        let mut todo: Vec<Pos> = seen.iter().copied().collect();
+        while let Some(p) = todo.pop() {
+            if p.1 < max - depth {
+                return None;
+            }
+            for d in [LEFT, RIGHT, BELOW] {
+                let (x, y) = add(p, d);
+                if (0..=6).contains(&x)
+                    && y >= 1
+                    && !self.rocks.contains((x, y))
+                    && seen.insert((x, y))
+                {
+                    todo.push((x, y));
+                }
+            }
+        }
+        let mut cells: Vec<Pos> = seen.into_iter().map(|(x, y)| (x, y - max)).collect();
+        cells.sort_unstable();
+        Some(cells)
+    }
+
+    fn step(&mut self) -> State {
+        let bottom = self.max_height();
+        let mut piece = Rock::new(self.next_rock_type, (2, bottom + 4));
+
+        self.next_rock_type = match self.next_rock_type {
+            RockType::Minus => RockType::Plus,
+            RockType::Plus => RockType::Angle,
+            RockType::Angle => RockType::Stick,
+            RockType::Stick => RockType::Stone,
+            RockType::Stone => RockType::Minus,
+        };
+
+        loop {
+            let jet = self.jet.chars().nth(self.clock as usize).unwrap();
+            self.clock = (self.clock + 1) % self.jet.len() as u32;
+            match jet {
+                '<' if !piece.collides(LEFT, &self.rocks) => piece.shift(LEFT),
+                '>' if !piece.collides(RIGHT, &self.rocks) => piece.shift(RIGHT),
+                _ => {}
+            }
+            common::trace!(%jet, origin = ?piece.origin);
+
+            if piece.collides(BELOW, &self.rocks) {
+                for (x, y) in piece.points() {
+                    self.rocks.insert((x, y), ());
+                }
+                common::trace!(rock = ?piece.typ, origin = ?piece.origin, "at rest");
+                break;
+            } else {
+                piece.shift(BELOW);
+            }
+        }
+        State {
+            clock: self.clock,
+            top: self.reachable(50),
+            next_rock_type: self.next_rock_type,
+        }
+    }
+}
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut jet = None;
+        for line in lines(Self::DAY, input.trim_end()) {
+            if jet.is_some() {
+                return Err(line.mismatch("a single line"));
+            }
+            if let Some((i, c)) = line
+                .text
+                .char_indices()
+                .find(|&(_, c)| c != '<' && c != '>')
+            {
+                return Err(line.error(i + 1, "`<` or `>`", format!("`{}`", c)));
+            }
+            jet = Some(line.text.to_string());
+        }
+        jet.ok_or_else(|| ParseError::end_of_input(Self::DAY, input, "a jet pattern"))
+    }
+
+    fn part1(jet: &Self::Input) -> Answer {
+        let mut cave = Cave::new(jet);
+        for rock in 1..=2022 {
+            let _rock = common::span!("rock", rock);
+            cave.step();
+        }
+        common::trace!("\n{}", cave);
+        cave.max_height().into()
+    }
+
+    fn part2(jet: &Self::Input) -> Answer {
+        tower_height(jet, 1000000000000).into()
+    }
+}
+
+/// Height of the tower after `rocks` rocks have fallen, skipping ahead as soon as the top of
+/// the tower repeats itself.
+pub fn tower_height(jet: &str, rocks: u64) -> u64 {
+    let mut cave = Cave::new(jet);
+    let mut states = HashMap::<State, u64>::new();
+    // Height of the tower after each rock
+    let mut heights = vec![0];
+    for count in 1..=rocks {
+        let _rock = common::span!("rock", rock = count);
+        let state = cave.step();
+        let h = cave.max_height() as u64;
+        heights.push(h);
+        common::trace!(?state);
+        if state.top.is_none() {
+            continue;
+        }
+        if let Some(&n0) = states.get(&state) {
+            // Loop found
+            let n = count;
+            let h0 = heights[n0 as usize];
+            common::debug!(n0, h0, n, h, "loop found");
+            let k = (rocks - n0) / (n - n0);
+            let r = (rocks - n0) % (n - n0);
+            return k * (h - h0) + heights[(n0 + r) as usize];
+        }
+        states.insert(state, count);
+    }
+    heights[rocks as usize]
+}
+
+impl Visualize for Day17 {
+    /// A frame per rock at rest, showing the top of the tower. Part 2 stops once the tower
+    /// starts repeating itself.
+    fn visualize(jet: &Self::Input, part: u8, frame: &mut Frames) {
+        let mut cave = Cave::new(jet);
+        let mut states = HashSet::new();
+        for count in 1.. {
+            let state = cave.step();
+            frame(&|| Frame::new((0, 0), &cave.top(40)));
+            if (part == 1 && count == 2022)
+                || (part != 1 && state.top.is_some() && !states.insert(state))
+            {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use regex::Regex;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use common::{lines, Answer, ParseError, Solution};
+
+pub struct Day18;
+
+pub type Cube = (i32, i32, i32);
+
+#[derive(Debug)]
+struct Bounds {
+    min_x: i32,
+    max_x: i32,
+    min_y: i32,
+    max_y: i32,
+    min_z: i32,
+    max_z: i32,
+}
+
+impl Bounds {
+    fn is_outside(&self, p: &Cube) -> bool {
+        let &(x, y, z) = p;
+        x < self.min_x
+            || x > self.max_x
+            || y < self.min_y
+            || y > self.max_y
+            || z < self.min_z
+            || z > self.max_z
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Cube>, ParseError> {
+    let re = Regex::new(r"^(\d+),(\d+),(\d+)$").unwrap();
+    let mut v = Vec::new();
+    for line in lines(Day18::DAY, input) {
+        let captures = re
+            .captures(line.text)
+            .ok_or_else(|| line.mismatch("a cube like `2,2,2`"))?;
+        let coordinate = |i| line.parse::<i32>(captures.get(i).unwrap().as_str(), "a coordinate");
+        v.push((coordinate(1)?, coordinate(2)?, coordinate(3)?));
+    }
+    if v.is_empty() {
+        return Err(ParseError::end_of_input(Day18::DAY, input, "a cube"));
+    }
+    Ok(v)
+}
+
+fn is_trapped(p: &Cube, scan: &[Cube], bounds: &Bounds, visited: &mut HashMap<Cube, bool>) -> bool {
+    common::trace!(cube = ?p, visited = visited.len());
+    if visited.contains_key(p) {
+        return visited[p];
+    }
+    let mut already_stacked = HashSet::new();
+    let mut stack = Vec::<Cube>::new();
+    stack.push(*p);
+    while let Some(p) = stack.pop() {
+        common::trace!(cube = ?p, stack = stack.len());
+        if visited.contains_key(&p) && !visited[&p] {
+            return false;
+        }
+        if bounds.is_outside(&p) {
+            return false;
+        }
+        if scan.contains(&p) {
+            continue;
+        }
+        if already_stacked.contains(&p) {
+            continue;
+        }
+        already_stacked.insert(p);
+        stack.push((p.0 + 1, p.1, p.2));
+        stack.push((p.0 - 1, p.1, p.2));
+        stack.push((p.0, p.1 + 1, p.2));
+        stack.push((p.0, p.1 - 1, p.2));
+        stack.push((p.0, p.1, p.2 + 1));
+        stack.push((p.0, p.1, p.2 - 1));
+    }
+    true
+}
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = Vec<Cube>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(v: &Self::Input) -> Answer {
+        let mut count: u32 = 6 * v.len() as u32;
+        for (i, &(x1, y1, z1)) in v.iter().enumerate() {
+            for &(x2, y2, z2) in &v[i + 1..] {
+                if x1.abs_diff(x2) + y1.abs_diff(y2) + z1.abs_diff(z2) == 1 {
+                    count -= 2;
+                }
+            }
+        }
+        count.into()
+    }
+
+    fn part2(scan: &Self::Input) -> Answer {
+        let mut count: u32 = 0;
+        let bounds = Bounds {
+            min_x: scan.iter().map(|&(x, _, _)| x).min().unwrap(),
+            max_x: scan.iter().map(|&(x, _, _)| x).max().unwrap(),
+            min_y: scan.iter().map(|&(_, y, _)| y).min().unwrap(),
+            max_y: scan.iter().map(|&(_, y, _)| y).max().unwrap(),
+            min_z: scan.iter().map(|&(_, _, z)| z).min().unwrap(),
+            max_z: scan.iter().map(|&(_, _, z)| z).max().unwrap(),
+        };
+        let mut visited = HashMap::<Cube, bool>::new();
+        for &(x, y, z) in scan {
+            common::cancel::checkpoint();
+            count += if !is_trapped(&(x + 1, y, z), scan, &bounds, &mut visited) {
+                visited.insert((x + 1, y, z), false);
+                1
+            } else {
+                visited.insert((x + 1, y, z), true);
+                0
+            };
+            count += if !is_trapped(&(x - 1, y, z), scan, &bounds, &mut visited) {
+                visited.insert((x - 1, y, z), false);
+                1
+            } else {
+                visited.insert((x - 1, y, z), true);
+                0
+            };
+            count += if !is_trapped(&(x, y + 1, z), scan, &bounds, &mut visited) {
+                visited.insert((x, y + 1, z), false);
+                1
+            } else {
+                visited.insert((x, y + 1, z), true);
+                0
+            };
+            count += if !is_trapped(&(x, y - 1, z), scan, &bounds, &mut visited) {
+                visited.insert((x, y - 1, z), false);
+                1
+            } else {
+                visited.insert((x, y - 1, z), true);
+                0
+            };
+            count += if !is_trapped(&(x, y, z + 1), scan, &bounds, &mut visited) {
+                visited.insert((x, y, z + 1), false);
+                1
+            } else {
+                visited.insert((x, y, z + 1), true);
+                0
+            };
+            count += if !is_trapped(&(x, y, z - 1), scan, &bounds, &mut visited) {
+                visited.insert((x, y, z - 1), false);
+                1
+            } else {
+                visited.insert((x, y, z - 1), true);
+                0
+            };
+            common::trace!(count);
+        }
+        count.into()
+    }
+}
@@ -0,0 +1,94 @@
+use common::{lines, Answer, ParseError, Solution};
+
+pub struct Day20;
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Item {
+    value: i64,
+    initial_idx: usize,
+}
+
+fn parse(input: &str) -> Result<Vec<Item>, ParseError> {
+    let mut values = Vec::<Item>::new();
+    for (idx, line) in lines(Day20::DAY, input).enumerate() {
+        let v = line.parse::<i32>(line.text, "an integer")?;
+        values.push(Item {
+            value: v as i64,
+            initial_idx: idx,
+        });
+    }
+    if values.len() < 2 {
+        return Err(ParseError::end_of_input(
+            Day20::DAY,
+            input,
+            "at least two numbers",
+        ));
+    }
+    if values.iter().all(|item| item.value != 0) {
+        return Err(ParseError::end_of_input(Day20::DAY, input, "a `0`"));
+    }
+    Ok(values)
+}
+
+fn mix(values: &mut Vec<Item>) {
+    let len = values.len() as i64;
+    for i in 0..len as usize {
+        let (idx, item) = values
+            .iter()
+            .enumerate()
+            .find(|&(_, item)| item.initial_idx == i)
+            .unwrap();
+        let v = item.value;
+        let mut n_idx = v + idx as i64;
+        n_idx %= len - 1;
+        if n_idx < 0 {
+            n_idx = len + n_idx - 1;
+        }
+        common::trace!(value = v, from = idx, to = n_idx);
+        let item = values.remove(idx);
+        values.insert(n_idx as usize, item);
+    }
+}
+
+fn find_coordinates(values: &[Item]) -> i64 {
+    let len = values.len();
+    let (idx_0, _) = values
+        .iter()
+        .enumerate()
+        .find(|&(_, item)| item.value == 0)
+        .unwrap();
+    let idx_1000 = (idx_0 + 1000) % len;
+    let idx_2000 = (idx_0 + 2000) % len;
+    let idx_3000 = (idx_0 + 3000) % len;
+    common::debug!(idx_0, idx_1000, idx_2000, idx_3000);
+    values[idx_1000].value + values[idx_2000].value + values[idx_3000].value
+}
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = Vec<Item>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let mut values = input.clone();
+        mix(&mut values);
+        find_coordinates(&values).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let mut values = input.clone();
+        for item in values.iter_mut() {
+            item.value *= 811589153;
+        }
+        for round in 1..=10 {
+            let _round = common::span!("round", round);
+            mix(&mut values);
+        }
+
+        find_coordinates(&values).into()
+    }
+}
@@ -0,0 +1,284 @@
+// Numbers are only `Copy` without the `bigint` feature
+#![cfg_attr(not(feature = "bigint"), allow(clippy::clone_on_copy))]
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, char, digit1},
+    combinator::{map_res, opt, recognize},
+    multi::many1_count,
+    sequence::pair,
+    IResult,
+};
+use std::collections::HashMap;
+
+use common::overflow::checked;
+use common::{lines, Answer, ParseError, Solution};
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
+
+pub struct Day21;
+
+#[cfg(not(feature = "bigint"))]
+type Number = i64;
+#[cfg(feature = "bigint")]
+type Number = num_bigint::BigInt;
+
+/// `lhs op rhs`, giving up the solver when it overflows or divides by zero.
+fn apply(lhs: &Number, op: char, rhs: &Number) -> Number {
+    let result = match op {
+        '+' => CheckedAdd::checked_add(lhs, rhs),
+        '-' => CheckedSub::checked_sub(lhs, rhs),
+        '*' => CheckedMul::checked_mul(lhs, rhs),
+        _ => CheckedDiv::checked_div(lhs, rhs),
+    };
+    checked(result, Day21::DAY, || format!("{} {} {}", lhs, op, rhs))
+}
+
+fn parse_monkey_id(input: &str) -> IResult<&str, &str> {
+    recognize(many1_count(alpha1))(input)
+}
+
+fn parse_operation(input: &str) -> IResult<&str, MonkeyAction> {
+    let (input, id1) = parse_monkey_id(input)?;
+    let (input, operation) =
+        alt((tag(" + "), alt((tag(" - "), alt((tag(" * "), tag(" / ")))))))(input)?;
+    let (input, id2) = parse_monkey_id(input)?;
+    match operation {
+        " + " => Ok((
+            input,
+            MonkeyAction::Add(String::from(id1), String::from(id2)),
+        )),
+        " - " => Ok((
+            input,
+            MonkeyAction::Sub(String::from(id1), String::from(id2)),
+        )),
+        " * " => Ok((
+            input,
+            MonkeyAction::Mult(String::from(id1), String::from(id2)),
+        )),
+        " / " => Ok((
+            input,
+            MonkeyAction::Div(String::from(id1), String::from(id2)),
+        )),
+        _ => {
+            panic!("Invalid operation")
+        }
+    }
+}
+
+fn parse_number(input: &str) -> IResult<&str, MonkeyAction> {
+    let number = recognize(pair(opt(char('-')), digit1));
+    let (input, n) = map_res(number, str::parse::<Number>)(input)?;
+    Ok((input, MonkeyAction::Number(n)))
+}
+
+fn parse_line(input: &str) -> IResult<&str, (&str, MonkeyAction)> {
+    let (input, id) = parse_monkey_id(input)?;
+    let (input, _) = tag(": ")(input)?;
+    let (input, action) = alt((parse_number, parse_operation))(input)?;
+    Ok((input, (id, action)))
+}
+
+fn parse(input: &str) -> Result<HashMap<String, MonkeyAction>, ParseError> {
+    let mut map = HashMap::new();
+    let mut operands = Vec::new();
+    for line in lines(Day21::DAY, input) {
+        let (id, action) = match parse_line(line.text) {
+            Ok(("", v)) => v,
+            Ok((rest, _)) => return Err(line.error_at(rest, "the end of the line")),
+            Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => {
+                return Err(line.error_at(e.input, "a monkey job"))
+            }
+            Err(nom::Err::Incomplete(_)) => unreachable!(),
+        };
+        if map.contains_key(id) {
+            return Err(line.error_at(id, "a new monkey"));
+        }
+        if let MonkeyAction::Add(a, b)
+        | MonkeyAction::Sub(a, b)
+        | MonkeyAction::Mult(a, b)
+        | MonkeyAction::Div(a, b) = &action
+        {
+            let (_, job) = line.text.split_once(": ").unwrap();
+            operands.push((line, &job[..a.len()], a.clone()));
+            operands.push((line, &job[job.len() - b.len()..], b.clone()));
+        }
+        map.insert(id.to_string(), action);
+    }
+    if let Some((line, at, _)) = operands.iter().find(|(_, _, id)| !map.contains_key(id)) {
+        return Err(line.error_at(at, "a known monkey"));
+    }
+    if !map.contains_key("root") {
+        return Err(ParseError::end_of_input(
+            Day21::DAY,
+            input,
+            "a `root` monkey",
+        ));
+    }
+    Ok(map)
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum MonkeyAction {
+    Number(Number),
+    Add(String, String),
+    Sub(String, String),
+    Mult(String, String),
+    Div(String, String),
+}
+
+impl MonkeyAction {
+    fn eval(&self, map: &HashMap<String, Self>) -> Number {
+        let (id1, op, id2) = match self {
+            Self::Number(n) => return n.clone(),
+            Self::Add(id1, id2) => (id1, '+', id2),
+            Self::Sub(id1, id2) => (id1, '-', id2),
+            Self::Mult(id1, id2) => (id1, '*', id2),
+            Self::Div(id1, id2) => (id1, '/', id2),
+        };
+        apply(
+            &map[id1.as_str()].eval(map),
+            op,
+            &map[id2.as_str()].eval(map),
+        )
+    }
+}
+
+fn reduce<'a>(
+    id: &'a str,
+    map: &'a HashMap<String, MonkeyAction>,
+    reduced: &mut HashMap<&'a str, Number>,
+) -> Option<Number> {
+    if id == "humn" {
+        return None;
+    }
+    match &map[id] {
+        MonkeyAction::Number(n) => {
+            reduced.insert(id, n.clone());
+            Some(n.clone())
+        }
+        MonkeyAction::Add(id1, id2) => {
+            let v1 = reduce(id1.as_str(), map, reduced);
+            let v2 = reduce(id2.as_str(), map, reduced);
+            match (v1, v2) {
+                (Some(v1), Some(v2)) => {
+                    let v = apply(&v1, '+', &v2);
+                    reduced.insert(id, v.clone());
+                    Some(v)
+                }
+                _ => None,
+            }
+        }
+        MonkeyAction::Sub(id1, id2) => {
+            let v1 = reduce(id1.as_str(), map, reduced);
+            let v2 = reduce(id2.as_str(), map, reduced);
+
+            match (v1, v2) {
+                (Some(v1), Some(v2)) => {
+                    let v = apply(&v1, '-', &v2);
+                    reduced.insert(id, v.clone());
+                    Some(v)
+                }
+                _ => None,
+            }
+        }
+        MonkeyAction::Mult(id1, id2) => {
+            let v1 = reduce(id1.as_str(), map, reduced);
+            let v2 = reduce(id2.as_str(), map, reduced);
+
+            match (v1, v2) {
+                (Some(v1), Some(v2)) => {
+                    let v = apply(&v1, '*', &v2);
+                    reduced.insert(id, v.clone());
+                    Some(v)
+                }
+                _ => None,
+            }
+        }
+        MonkeyAction::Div(id1, id2) => {
+            let v1 = reduce(id1.as_str(), map, reduced);
+            let v2 = reduce(id2.as_str(), map, reduced);
+
+            match (v1, v2) {
+                (Some(v1), Some(v2)) => {
+                    let v = apply(&v1, '/', &v2);
+                    reduced.insert(id, v.clone());
+                    Some(v)
+                }
+                _ => None,
+            }
+        }
+    }
+}
+
+fn resolve(map: &HashMap<String, MonkeyAction>, reduced: &HashMap<&str, Number>) -> Number {
+    let (lhs, rhs) = match &map["root"] {
+        MonkeyAction::Add(id1, id2)
+        | MonkeyAction::Sub(id1, id2)
+        | MonkeyAction::Mult(id1, id2)
+        | MonkeyAction::Div(id1, id2) => (id1.as_str(), id2.as_str()),
+        _ => panic!("Invalid"),
+    };
+    let (mut v, mut id) = if reduced.contains_key(lhs) {
+        (reduced[lhs].clone(), rhs)
+    } else {
+        (reduced[rhs].clone(), lhs)
+    };
+    common::debug!(reduced = reduced.len());
+    loop {
+        common::trace!(id, %v);
+
+        (v, id) = match &map[id] {
+            MonkeyAction::Number(_) => return v,
+            MonkeyAction::Add(lhs, rhs) => {
+                if reduced.contains_key(lhs.as_str()) {
+                    (apply(&v, '-', &reduced[lhs.as_str()]), rhs.as_str()) // v = x + ?
+                } else {
+                    (apply(&v, '-', &reduced[rhs.as_str()]), lhs.as_str()) // v = ? + x
+                }
+            }
+            MonkeyAction::Sub(lhs, rhs) => {
+                if reduced.contains_key(lhs.as_str()) {
+                    (apply(&reduced[lhs.as_str()], '-', &v), rhs.as_str()) // v = x - ?
+                } else {
+                    (apply(&v, '+', &reduced[rhs.as_str()]), lhs.as_str()) // v = ? - x
+                }
+            }
+            MonkeyAction::Mult(lhs, rhs) => {
+                if reduced.contains_key(lhs.as_str()) {
+                    (apply(&v, '/', &reduced[lhs.as_str()]), rhs.as_str()) // v = x * ?
+                } else {
+                    (apply(&v, '/', &reduced[rhs.as_str()]), lhs.as_str()) // v = ? * x
+                }
+            }
+            MonkeyAction::Div(lhs, rhs) => {
+                if reduced.contains_key(lhs.as_str()) {
+                    (apply(&reduced[lhs.as_str()], '/', &v), rhs.as_str()) // v = x / ?
+                } else {
+                    (apply(&v, '*', &reduced[rhs.as_str()]), lhs.as_str()) // v = ? / x
+                }
+            }
+        }
+    }
+}
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = HashMap<String, MonkeyAction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        map["root"].eval(map).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        let mut reduced = HashMap::<&str, Number>::new();
+        reduce("root", map, &mut reduced);
+        resolve(map, &reduced).into()
+    }
+}
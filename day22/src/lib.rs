@@ -0,0 +1,370 @@
+use nom::{
+    branch::alt, bytes::complete::tag, character::complete::digit1, combinator::recognize,
+    multi::many1, multi::many1_count, IResult,
+};
+
+use common::{lines, Answer, Assumption, ParseError, Solution};
+use grid::{Pos, SparseGrid};
+
+pub struct Day22;
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    fn turn(&self, clockwise: bool) -> Self {
+        match (self, clockwise) {
+            (Self::North, true) => Direction::East,
+            (Self::North, false) => Direction::West,
+            (Self::East, true) => Direction::South,
+            (Self::East, false) => Direction::North,
+            (Self::South, true) => Direction::West,
+            (Self::South, false) => Direction::East,
+            (Self::West, true) => Direction::North,
+            (Self::West, false) => Direction::South,
+        }
+    }
+
+    fn as_int(&self) -> i32 {
+        match self {
+            Self::East => 0,
+            Self::South => 1,
+            Self::West => 2,
+            Self::North => 3,
+        }
+    }
+}
+
+/// One step of the path: turn left, turn right, or move forward.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Instruction {
+    Left,
+    Right,
+    Forward(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+enum Tile {
+    Open,
+    Wall,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Board {
+    tiles: SparseGrid<Tile>,
+    size: usize, // Only for part 2
+}
+
+impl Board {
+    fn new() -> Board {
+        Board {
+            tiles: SparseGrid::new(),
+            size: 0,
+        }
+    }
+
+    fn is_wall(&self, pos: Pos) -> bool {
+        self.tiles[pos] == Tile::Wall
+    }
+
+    /// First and last tiles of the row or column.
+    fn borders<'a>(line: impl Iterator<Item = (Pos, &'a Tile)>) -> (Pos, Pos) {
+        let mut line = line.map(|(p, _)| p);
+        let first = line.next().unwrap();
+        (first, line.last().unwrap_or(first))
+    }
+
+    fn walk_one(&self, pos: Pos, dir: &Direction) -> Pos {
+        let (x, y) = pos;
+        let ((xmin, _), (xmax, _)) = Board::borders(self.tiles.row(y));
+        let ((_, ymin), (_, ymax)) = Board::borders(self.tiles.column(x));
+        match dir {
+            Direction::North if y == ymin => (x, ymax),
+            Direction::North => (x, y - 1),
+            Direction::East if x == xmax => (xmin, y),
+            Direction::East => (x + 1, y),
+            Direction::South if pos.1 == ymax => (x, ymin),
+            Direction::South => (x, y + 1),
+            Direction::West if x == xmin => (xmax, y),
+            Direction::West => (x - 1, y),
+        }
+    }
+
+    fn walk(&self, pos: Pos, steps: usize, dir: &Direction) -> Pos {
+        let mut current = pos;
+        let mut next: Pos;
+        for _ in 0..steps {
+            next = self.walk_one(current, dir);
+            common::trace!(?next);
+            if self.is_wall(next) {
+                break;
+            }
+            current = next;
+        }
+        current
+    }
+
+    // Solve the example puzzle
+    #[allow(dead_code)]
+    fn walk_one_part_2_example(&self, pos: Pos, dir: &Direction) -> (Pos, Direction) {
+        let (x, y) = pos;
+        match (x, y, dir) {
+            (x, 0, Direction::North) if (8..=11).contains(&x) => ((11 - x, 4), Direction::South), // 1 -> 2
+            (x, 4, Direction::North) if x <= 3 => ((11 - x, 0), Direction::South), // 2 -> 1
+            (8, y, Direction::West) if y <= 3 => ((4 - y, 4), Direction::South),   // 1 -> 3
+            (x, 4, Direction::North) if (4..=7).contains(&x) => ((8, x - 4), Direction::East), // 3 -> 1
+            (11, y, Direction::East) if y <= 3 => ((15, 11 - y), Direction::West), // 1 -> 6
+            (15, y, Direction::East) if y >= 8 => ((11, 11 - y), Direction::West), // 6 -> 1
+            (x, 7, Direction::South) if x <= 3 => ((11 - x, 11), Direction::North), // 2 -> 5
+            (x, 11, Direction::South) if (8..=11).contains(&x) => ((11 - x, 7), Direction::North), // 5 -> 2
+            (0, y, Direction::West) if (4..=7).contains(&y) => ((19 - y, 11), Direction::North), // 2 -> 6
+            (x, 11, Direction::South) if x >= 12 => ((0, 19 - x), Direction::East), // 6 -> 2
+            (x, 7, Direction::South) if (4..=7).contains(&x) => ((8, 15 - y), Direction::East), // 3 -> 5
+            (8, y, Direction::West) if y >= 8 => ((15 - x, 7), Direction::North), // 5 -> 3
+            (11, y, Direction::East) if (4..=7).contains(&y) => ((19 - y, 8), Direction::South), // 4 -> 6
+            (x, 8, Direction::North) if x >= 12 => ((1, 19 - x), Direction::West), // 6 -> 4
+            (x, y, Direction::North) => ((x, y - 1), Direction::North),
+            (x, y, Direction::East) => ((x + 1, y), Direction::East),
+            (x, y, Direction::South) => ((x, y + 1), Direction::South),
+            (x, y, Direction::West) => ((x - 1, y), Direction::West),
+        }
+    }
+
+    /**
+     * Compute the plane coordinates of the next square when moving one step in direction `dir` from position `pos`
+     * This function also handle warping from one face to another. However this solution only works for my puzzle with
+     * this given faces relative positions
+     *
+     *   1122
+     *   1122
+     *   33
+     *   33
+     * 4455
+     * 4455
+     * 66
+     */
+    fn walk_one_part_2(&self, pos: Pos, dir: &Direction) -> (Pos, Direction) {
+        let (x, y) = pos;
+        match (x, y, dir) {
+            (50, y, Direction::West) if y <= 49 => ((0, 149 - y), Direction::East), // 1 -> 4
+            (0, y, Direction::West) if (100..=149).contains(&y) => ((50, 149 - y), Direction::East), // 4 -> 1
+            (x, 0, Direction::North) if (50..=99).contains(&x) => ((0, x + 100), Direction::East), // 1 -> 6
+            (0, y, Direction::West) if (150..=199).contains(&y) => ((y - 100, 0), Direction::South), // 6 -> 1
+            (x, 49, Direction::South) if (100..=149).contains(&x) => {
+                ((99, x - 50), Direction::West)
+            } // 2 -> 3
+            (99, y, Direction::East) if (50..=99).contains(&y) => ((y + 50, 49), Direction::North), // 3 -> 2
+            (149, y, Direction::East) if y <= 49 => ((99, 149 - y), Direction::West), // 2 -> 5
+            (99, y, Direction::East) if (100..=149).contains(&y) => {
+                ((149, 149 - y), Direction::West)
+            } // 5 -> 2
+            (x, 0, Direction::North) if (100..=149).contains(&x) => {
+                ((x - 100, 199), Direction::North)
+            } // 2 -> 6
+            (x, 199, Direction::South) if x <= 49 => ((x + 100, 0), Direction::South), // 6 -> 2
+            (50, y, Direction::West) if (50..=99).contains(&y) => ((y - 50, 100), Direction::South), // 3 -> 4
+            (x, 100, Direction::North) if x <= 49 => ((50, x + 50), Direction::East), // 4 -> 3
+            (x, 149, Direction::South) if (50..=99).contains(&x) => {
+                ((49, x + 100), Direction::West)
+            } // 5 -> 6
+            (49, y, Direction::East) if y >= 150 => ((y - 100, 149), Direction::North), // 6 -> 5
+            (x, y, Direction::North) => ((x, y - 1), Direction::North),
+            (x, y, Direction::East) => ((x + 1, y), Direction::East),
+            (x, y, Direction::South) => ((x, y + 1), Direction::South),
+            (x, y, Direction::West) => ((x - 1, y), Direction::West),
+        }
+    }
+
+    // fn walk_part_2_test(
+    fn walk_part_2(&self, pos: Pos, steps: usize, dir: Direction) -> (Pos, Direction) {
+        let (mut current_pos, mut current_dir) = (pos, dir);
+        let (mut next_pos, mut next_dir): (Pos, Direction);
+        for _ in 0..steps {
+            (next_pos, next_dir) = self.walk_one_part_2(current_pos, &current_dir);
+            common::trace!(next = ?next_pos, dir = ?next_dir);
+            if self.is_wall(next_pos) {
+                break;
+            }
+            (current_pos, current_dir) = (next_pos, next_dir);
+        }
+        (current_pos, current_dir)
+    }
+}
+
+fn parse_instructions(input: &str) -> IResult<&str, Vec<&str>> {
+    let (input, items) = many1(alt((
+        recognize(many1_count(digit1)),
+        alt((tag("R"), tag("L"))),
+    )))(input)?;
+    Ok((input, items))
+}
+
+fn parse(input: &str) -> Result<(Board, Vec<Instruction>), ParseError> {
+    let mut board = Board::new();
+    let mut y = 0;
+    for line in lines(Day22::DAY, input) {
+        match line.text {
+            "" => {}
+            text if text.chars().next().unwrap().is_alphanumeric() => {
+                if y == 0 {
+                    return Err(line.mismatch("a row of the board"));
+                }
+                board.size = board.tiles.bounds().height() / 3;
+                common::debug!(tiles = board.tiles.len(), size = board.size, "board");
+                let instructions = match parse_instructions(text) {
+                    Ok(("", v)) => v,
+                    Ok((rest, _)) => {
+                        return Err(line.error_at(rest, "a number of tiles, `L` or `R`"))
+                    }
+                    Err(_) => return Err(line.mismatch("a path like `10R5L5`")),
+                };
+                return instructions
+                    .iter()
+                    .map(|&instr| match instr {
+                        "L" => Ok(Instruction::Left),
+                        "R" => Ok(Instruction::Right),
+                        v => line
+                            .parse::<usize>(v, "a number of tiles")
+                            .map(Instruction::Forward),
+                    })
+                    .collect::<Result<_, _>>()
+                    .map(|instructions| (board, instructions));
+            }
+            text => {
+                for (x, c) in text.chars().enumerate() {
+                    match c {
+                        '.' => {
+                            board.tiles.insert((x as i32, y), Tile::Open);
+                        }
+                        '#' => {
+                            board.tiles.insert((x as i32, y), Tile::Wall);
+                        }
+                        ' ' => {}
+                        c => {
+                            return Err(line.error(
+                                x + 1,
+                                "`.`, `#` or a space",
+                                format!("`{}`", c),
+                            ))
+                        }
+                    }
+                }
+                if board.tiles.row(y).next().is_none() {
+                    return Err(line.mismatch("a row with at least one tile"));
+                }
+                y += 1;
+            }
+        }
+    }
+    Err(ParseError::end_of_input(Day22::DAY, input, "a path"))
+}
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Input = (Board, Vec<Instruction>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (board, instructions) = input;
+        let mut pos = Board::borders(board.tiles.row(0)).0;
+        common::debug!(instructions = instructions.len(), start = ?pos);
+        let mut dir = Direction::East;
+        for instr in instructions {
+            common::trace!(?instr);
+            match instr {
+                Instruction::Left => dir = dir.turn(false),
+                Instruction::Right => dir = dir.turn(true),
+                &Instruction::Forward(v) => {
+                    pos = board.walk(pos, v, &dir);
+                    common::trace!(?pos);
+                }
+            }
+        }
+        ((pos.1 + 1) * 1000 + 4 * (pos.0 + 1) + dir.as_int()).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (board, instructions) = input;
+        let mut pos = Board::borders(board.tiles.row(0)).0;
+        let mut dir = Direction::East;
+        common::debug!(instructions = instructions.len(), start = ?pos, ?dir);
+        for instr in instructions {
+            common::trace!(?instr);
+            match instr {
+                Instruction::Left => dir = dir.turn(false),
+                Instruction::Right => dir = dir.turn(true),
+                &Instruction::Forward(v) => {
+                    // (pos, dir) = board.walk_part_2_test(pos, v, dir);
+                    (pos, dir) = board.walk_part_2(pos, v, dir);
+                    common::trace!(?pos, ?dir);
+                }
+            }
+        }
+
+        ((pos.1 + 1) * 1000 + 4 * (pos.0 + 1) + dir.as_int()).into()
+    }
+
+    fn check(input: &str) -> Vec<Assumption> {
+        vec![Assumption::new(
+            "the board is a net 3 faces wide of 50 tiles, laid out like `walk_one_part_2`",
+            check_net(input),
+        )]
+    }
+}
+
+/// Side of a face of the cube, in tiles.
+const FACE: usize = 50;
+
+/// First and last face covered by each band of rows of the net handled by part 2.
+const NET: [(usize, usize); 4] = [(1, 2), (1, 1), (0, 1), (0, 0)];
+
+/// Errors for the first row of each band that does not span its faces, and for a board
+/// that is not 4 faces high.
+fn check_net(input: &str) -> Vec<ParseError> {
+    let mut violations = Vec::new();
+    let mut rows = lines(Day22::DAY, input).take_while(|line| !line.text.is_empty());
+    for (band, &(first, last)) in NET.iter().enumerate() {
+        let expected = format!(
+            "tiles from column {} to {}",
+            first * FACE + 1,
+            (last + 1) * FACE
+        );
+        for _ in 0..FACE {
+            let Some(line) = rows.next() else {
+                let height = format!("a board {} rows high", NET.len() * FACE);
+                violations.push(ParseError::end_of_input(Day22::DAY, input, height));
+                return violations;
+            };
+            let start = line.text.chars().take_while(|&c| c == ' ').count();
+            let end = line.text.trim_end().chars().count();
+            if (start, end) != (first * FACE, (last + 1) * FACE) {
+                let found = format!("tiles from column {} to {}", start + 1, end);
+                violations.push(line.error(start + 1, expected, found));
+                // The rest of the band is likely off too
+                rows.by_ref()
+                    .take(FACE * (band + 1) - line.number)
+                    .for_each(drop);
+                break;
+            }
+        }
+    }
+    if let Some(line) = rows.next() {
+        violations.push(line.mismatch(format!(
+            "the end of the board after {} rows",
+            NET.len() * FACE
+        )));
+    }
+    violations
+}
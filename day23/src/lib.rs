@@ -0,0 +1,147 @@
+use multimap::MultiMap;
+
+use common::{Answer, ParseError, Solution};
+use grid::{neighbours_8, Pos, SparseGrid};
+use visualize::{Frame, Frames, Visualize};
+
+pub struct Day23;
+
+fn is_alone(pos: Pos, elves: &SparseGrid<()>) -> bool {
+    neighbours_8(pos).all(|p| !elves.contains(p))
+}
+
+fn try_move(pos: Pos, dir: u32, elves: &SparseGrid<()>) -> Option<Pos> {
+    let (x, y) = pos;
+    match dir % 4 {
+        0 if [(x - 1, y - 1), (x, y - 1), (x + 1, y - 1)]
+            .iter()
+            .fold(true, |acc, &(x, y)| acc & !elves.contains((x, y))) =>
+        {
+            Some((x, y - 1))
+        }
+        1 if [(x - 1, y + 1), (x, y + 1), (x + 1, y + 1)]
+            .iter()
+            .fold(true, |acc, &(x, y)| acc & !elves.contains((x, y))) =>
+        {
+            Some((x, y + 1))
+        }
+        2 if [(x - 1, y - 1), (x - 1, y), (x - 1, y + 1)]
+            .iter()
+            .fold(true, |acc, &(x, y)| acc & !elves.contains((x, y))) =>
+        {
+            Some((x - 1, y))
+        }
+        3 if [(x + 1, y - 1), (x + 1, y), (x + 1, y + 1)]
+            .iter()
+            .fold(true, |acc, &(x, y)| acc & !elves.contains((x, y))) =>
+        {
+            Some((x + 1, y))
+        }
+        _ => None,
+    }
+}
+
+fn parse(input: &str) -> Result<SparseGrid<()>, ParseError> {
+    let elves = SparseGrid::parse(Day23::DAY, input, ".", "`#` or `.`", |_, c| {
+        (c == '#').then_some(())
+    })?;
+    if elves.is_empty() {
+        return Err(ParseError::end_of_input(Day23::DAY, input, "an elf `#`"));
+    }
+    Ok(elves)
+}
+
+fn render(elves: &SparseGrid<()>) -> String {
+    elves.render(|_, elf| if elf.is_some() { '#' } else { '.' })
+}
+
+/// Play round `clock`, returning the number of elves that did not need to move.
+fn round(elves: &mut SparseGrid<()>, clock: u32) -> usize {
+    let mut fixed_elves = 0usize;
+    let mut moves = MultiMap::new();
+    // Find potential next positions
+    for (pos, _) in elves.iter() {
+        if is_alone(pos, elves) {
+            fixed_elves += 1;
+        } else {
+            for off in 0..4u32 {
+                if let Some(p) = try_move(pos, clock + off, elves) {
+                    moves.insert(p, pos);
+                    break;
+                }
+            }
+        }
+    }
+    // Acutal moves
+    for pos in moves.keys() {
+        let positions = moves.get_vec(pos).unwrap();
+        if positions.len() == 1 {
+            elves.remove(positions[0]);
+            elves.insert(*pos, ());
+        }
+    }
+    fixed_elves
+}
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = SparseGrid<()>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let mut elves = input.clone();
+        common::trace!("\n{}", render(&elves));
+        let mut clock = 0;
+        loop {
+            let _round = common::span!("round", round = clock);
+            let fixed_elves = round(&mut elves, clock);
+            common::trace!(fixed_elves, "\n{}", render(&elves));
+
+            clock += 1;
+
+            if fixed_elves == elves.len() || clock == 10 {
+                break;
+            }
+        }
+        elves.shrink_bounds();
+        (elves.bounds().area() - elves.len()).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let mut elves = input.clone();
+        let mut clock = 0;
+        loop {
+            let _round = common::span!("round", round = clock);
+            let fixed_elves = round(&mut elves, clock);
+            common::trace!(fixed_elves, moved = elves.len() - fixed_elves);
+
+            clock += 1;
+
+            if fixed_elves == elves.len() {
+                break;
+            }
+        }
+        clock.into()
+    }
+}
+
+impl Visualize for Day23 {
+    /// A frame per round, the view following the elves as they spread.
+    fn visualize(input: &Self::Input, part: u8, frame: &mut Frames) {
+        let mut elves = input.clone();
+        let mut clock = 0;
+        loop {
+            let fixed_elves = round(&mut elves, clock);
+            elves.shrink_bounds();
+            frame(&|| Frame::from_grid(&elves, |_, elf| if elf.is_some() { '#' } else { '.' }));
+            clock += 1;
+            if fixed_elves == elves.len() || (part == 1 && clock == 10) {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use num::integer::lcm;
+use std::collections::HashSet;
+
+use common::{lines, Answer, ParseError, Solution};
+use grid::{Bounds, Grid, Pos};
+use search::{astar, Path};
+use visualize::{Frame, Frames, Visualize};
+
+pub struct Day24;
+
+type Blizzard = (i32, i32, char);
+type Node = Pos;
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Basin {
+    bounds: (i32, i32),
+    enter: Node,
+    exit: Node,
+    cycle: i32,
+    /// Walls and blizzards at minute 0.
+    blizzards: Vec<Blizzard>,
+    /// Squares taken by a wall or a blizzard, at each minute of the cycle.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    blizzard_maps: Vec<Grid<bool>>,
+}
+
+fn mp(x: i32, p: i32) -> i32 {
+    let m = (x - 1) % p;
+    if m < 0 {
+        m + p + 1
+    } else {
+        m + 1
+    }
+}
+
+/// Position of a blizzard, or a wall, at minute `t`.
+fn blizzard_at(b: Blizzard, t: i32, x_max: i32, y_max: i32) -> Node {
+    match b.2 {
+        '>' => (mp(b.0 + t, x_max), b.1),
+        '<' => (mp(b.0 - t, x_max), b.1),
+        'v' => (b.0, mp(b.1 + t, y_max)),
+        '^' => (b.0, mp(b.1 - t, y_max)),
+        _ => (b.0, b.1),
+    }
+}
+
+impl Basin {
+    fn new(init: HashSet<Blizzard>) -> Basin {
+        let x_max = init.iter().map(|&(x, _, _)| x).max().unwrap() - 1;
+        let y_max = init.iter().map(|&(_, y, _)| y).max().unwrap() - 1;
+        let maps = Basin::init_blizzard_maps(&init, x_max, y_max, lcm(x_max, y_max));
+        Basin {
+            bounds: (x_max, y_max),
+            enter: (1, 0),
+            exit: (x_max, y_max + 1),
+            cycle: lcm(x_max, y_max),
+            blizzards: init.into_iter().collect(),
+            blizzard_maps: maps,
+        }
+    }
+
+    fn x_max(&self) -> i32 {
+        self.bounds.0
+    }
+
+    fn y_max(&self) -> i32 {
+        self.bounds.1
+    }
+
+    fn init_blizzard_maps(
+        init: &HashSet<Blizzard>,
+        x_max: i32,
+        y_max: i32,
+        cycle: i32,
+    ) -> Vec<Grid<bool>> {
+        let bounds = Bounds::new((0, 0), (x_max + 1, y_max + 1));
+        let mut positions = Vec::new();
+        for t in 0..cycle {
+            let mut new_map = Grid::<bool>::new(bounds, false);
+            for &b in init {
+                new_map[blizzard_at(b, t, x_max, y_max)] = true;
+            }
+            positions.push(new_map);
+        }
+        positions
+    }
+
+    /// The basin at minute `clock`, stacked blizzards shown by their number.
+    fn render(&self, clock: i32, expedition: Node) -> Grid<char> {
+        let bounds = Bounds::new((0, 0), (self.x_max() + 1, self.y_max() + 1));
+        let mut map = Grid::<char>::new(bounds, '.');
+        for &b in &self.blizzards {
+            let p = blizzard_at(b, clock, self.x_max(), self.y_max());
+            map[p] = match map[p] {
+                '.' => b.2,
+                c if c.is_ascii_digit() => (c as u8 + 1) as char,
+                _ => '2',
+            };
+        }
+        map[expedition] = 'E';
+        map
+    }
+
+    fn neighbours(&self, pos: &Node, clock: i32) -> Vec<Node> {
+        let map = &self.blizzard_maps[(clock % self.cycle) as usize];
+        std::iter::once(*pos)
+            .chain(map.neighbours_4(*pos))
+            .filter(|&p| !map[p])
+            .collect()
+    }
+}
+
+fn parse(input: &str) -> Result<Basin, ParseError> {
+    let width = input.lines().next().map_or(0, |l| l.chars().count());
+    let height = input.lines().count();
+    if width < 3 || height < 3 {
+        return Err(ParseError::end_of_input(
+            Day24::DAY,
+            input,
+            "a basin of at least 3 by 3",
+        ));
+    }
+    let mut map = HashSet::<Blizzard>::new();
+    for (y, line) in lines(Day24::DAY, input).enumerate() {
+        if line.text.chars().count() != width {
+            return Err(line.mismatch(format!("a row of {} tiles", width)));
+        }
+        for (x, c) in line.text.chars().enumerate() {
+            let opening = (y == 0 && x == 1) || (y == height - 1 && x == width - 2);
+            let wall = !opening && (y == 0 || y == height - 1 || x == 0 || x == width - 1);
+            match c {
+                '#' if wall => {}
+                '.' if !wall => continue,
+                '>' | '<' | 'v' | '^' if !wall && !opening => {}
+                c => {
+                    let expected = match (wall, opening) {
+                        (true, _) => "a wall `#`",
+                        (_, true) => "an opening `.`",
+                        _ => "`.` or a blizzard",
+                    };
+                    return Err(line.error(x + 1, expected, format!("`{}`", c)));
+                }
+            }
+            map.insert((x as i32, y as i32, c));
+        }
+    }
+    Ok(Basin::new(map))
+}
+
+/// Fastest way from `start` to `end`, leaving at `start_clock`. The states of the path are
+/// the positions of the expedition with the minute, modulo the blizzards cycle.
+fn trip(start: Node, end: Node, start_clock: i32, basin: &Basin) -> Option<Path<(Node, i32), i32>> {
+    // The blizzards repeat every cycle, so does the state of the expedition
+    astar(
+        (start, start_clock % basin.cycle),
+        |&(current, clock)| {
+            let clock = clock + 1;
+            basin
+                .neighbours(&current, clock)
+                .into_iter()
+                .map(move |node| ((node, clock % basin.cycle), 1))
+        },
+        |&((x, y), _)| (end.0 - x).abs() + (end.1 - y).abs(),
+        |&(current, _)| current == end,
+    )
+}
+
+/// Minute at which `end` is reached at the earliest, leaving `start` at `start_clock`.
+fn earliest(start: Node, end: Node, start_clock: i32, basin: &Basin) -> i32 {
+    trip(start, end, start_clock, basin).map_or(i32::MIN, |path| start_clock + path.cost)
+}
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = Basin;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(basin: &Self::Input) -> Answer {
+        earliest((1, 0), (basin.x_max(), basin.y_max() + 1), 0, basin).into()
+    }
+
+    fn part2(basin: &Self::Input) -> Answer {
+        let t1 = earliest(basin.enter, basin.exit, 0, basin);
+        let t2 = earliest(basin.exit, basin.enter, t1, basin);
+        earliest(basin.enter, basin.exit, t2, basin).into()
+    }
+}
+
+impl Visualize for Day24 {
+    /// A frame per minute of the trip, or of the three trips of part 2.
+    fn visualize(basin: &Self::Input, part: u8, frame: &mut Frames) {
+        let legs = match part {
+            1 => vec![(basin.enter, basin.exit)],
+            _ => vec![
+                (basin.enter, basin.exit),
+                (basin.exit, basin.enter),
+                (basin.enter, basin.exit),
+            ],
+        };
+        let mut clock = 0;
+        frame(&|| Frame::from_grid(&basin.render(clock, basin.enter), |_, c| *c.unwrap()));
+        for (start, end) in legs {
+            let Some(path) = trip(start, end, clock, basin) else {
+                return;
+            };
+            for &(expedition, _) in &path.states[1..] {
+                clock += 1;
+                frame(&|| Frame::from_grid(&basin.render(clock, expedition), |_, c| *c.unwrap()));
+            }
+        }
+    }
+
+    fn color(c: char) -> [u8; 3] {
+        match c {
+            'E' => [110, 220, 110],
+            '<' | '>' | '^' | 'v' | '2'..='9' => [150, 190, 240],
+            '#' => [140, 140, 150],
+            _ => [20, 20, 30],
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use radix_fmt::radix_5;
+
+use common::{lines, Answer, Line, ParseError, Solution};
+
+pub struct Day25;
+
+fn from_snafu(line: &Line) -> Result<i64, ParseError> {
+    line.text
+        .chars()
+        .enumerate()
+        .map(|(i, c)| match c {
+            '0' => Ok(0),
+            '1' => Ok(1),
+            '2' => Ok(2),
+            '-' => Ok(-1),
+            '=' => Ok(-2),
+            c => Err(line.error(i + 1, "a SNAFU digit", format!("`{}`", c))),
+        })
+        .try_fold(None, |acc: Option<i64>, v| {
+            let v = v?;
+            match acc {
+                None => Ok(Some(v)),
+                Some(acc) => acc
+                    .checked_mul(5)
+                    .and_then(|acc| acc.checked_add(v))
+                    .map(Some)
+                    .ok_or_else(|| line.mismatch("a SNAFU number that fits in 64 bits")),
+            }
+        })?
+        .ok_or_else(|| line.mismatch("a SNAFU number"))
+}
+
+fn to_snafu(v: i64) -> String {
+    match radix_5(v)
+        .to_string()
+        .chars()
+        .rev()
+        .fold((String::new(), false), |acc, c| {
+            let (s, carry) = acc;
+            match c {
+                '0' if carry => (s + "1", false),
+                '0' => (s + "0", false),
+                '1' if carry => (s + "2", false),
+                '1' => (s + "1", false),
+                '2' if carry => (s + "=", true),
+                '2' => (s + "2", false),
+                '3' if carry => (s + "-", true),
+                '3' => (s + "=", true),
+                '4' if carry => (s + "0", true),
+                '4' => (s + "-", true),
+                _ => panic!("Invalid character"),
+            }
+        }) {
+        (s, true) => s + "1",
+        (s, false) => s,
+    }
+    .chars()
+    .rev()
+    .collect()
+}
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const PARTS: u8 = 1;
+
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        lines(Self::DAY, input)
+            .map(|line| from_snafu(&line))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        to_snafu(input.iter().sum::<i64>()).into()
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        unreachable!("day 25 has a single part")
+    }
+}
@@ -0,0 +1,9 @@
+#!/bin/sh
+# Seed the corpus of every target with the example inputs of its day.
+set -e
+cd "$(dirname "$0")"
+for day in ../day[0-9][0-9]; do
+    name=$(basename "$day")
+    mkdir -p "corpus/$name"
+    cp "$day"/*.test.txt "corpus/$name/"
+done
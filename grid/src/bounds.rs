@@ -0,0 +1,69 @@
+use crate::Pos;
+
+/// Inclusive rectangle of positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min: Pos,
+    pub max: Pos,
+}
+
+impl Bounds {
+    pub fn new(min: Pos, max: Pos) -> Bounds {
+        Bounds { min, max }
+    }
+
+    /// Rectangle of `width` by `height` positions starting at `(0, 0)`.
+    pub fn sized(width: usize, height: usize) -> Bounds {
+        Bounds {
+            min: (0, 0),
+            max: (width as i32 - 1, height as i32 - 1),
+        }
+    }
+
+    /// Single position rectangle.
+    pub fn point(p: Pos) -> Bounds {
+        Bounds { min: p, max: p }
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.0 - self.min.0 + 1).max(0) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.1 - self.min.1 + 1).max(0) as usize
+    }
+
+    pub fn area(&self) -> usize {
+        self.width() * self.height()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.area() == 0
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x >= self.min.0 && x <= self.max.0 && y >= self.min.1 && y <= self.max.1
+    }
+
+    /// Smallest rectangle containing both `self` and `p`.
+    pub fn extend(&self, (x, y): Pos) -> Bounds {
+        Bounds {
+            min: (self.min.0.min(x), self.min.1.min(y)),
+            max: (self.max.0.max(x), self.max.1.max(y)),
+        }
+    }
+
+    /// Row-major index of `p`, if it is inside.
+    pub fn index(&self, p: Pos) -> Option<usize> {
+        if !self.contains(p) {
+            return None;
+        }
+        Some((p.1 - self.min.1) as usize * self.width() + (p.0 - self.min.0) as usize)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let Bounds { min, max } = *self;
+        (min.1..=max.1).flat_map(move |y| (min.0..=max.0).map(move |x| (x, y)))
+    }
+}
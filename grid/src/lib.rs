@@ -0,0 +1,305 @@
+//! Two dimensional grids for the map based puzzles.
+//!
+//! Positions are `(x, y)` pairs of signed integers, `x` grows to the right and `y` downwards,
+//! like the lines of a puzzle input. A [`Grid`] is either [`Dense`], with a value for every
+//! position of a rectangle, or [`Sparse`], with values at some positions only.
+
+use common::{lines, ParseError};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
+
+mod bounds;
+#[cfg(feature = "serde")]
+mod serialize;
+mod storage;
+
+pub use bounds::Bounds;
+pub use storage::{Dense, Sparse, Storage};
+
+/// A position, `(x, y)`.
+pub type Pos = (i32, i32);
+
+pub const UP: Pos = (0, -1);
+pub const RIGHT: Pos = (1, 0);
+pub const DOWN: Pos = (0, 1);
+pub const LEFT: Pos = (-1, 0);
+
+/// Orthogonal directions, clockwise from up.
+pub const DIRECTIONS_4: [Pos; 4] = [UP, RIGHT, DOWN, LEFT];
+
+/// Orthogonal and diagonal directions, clockwise from up.
+pub const DIRECTIONS_8: [Pos; 8] = [UP, (1, -1), RIGHT, (1, 1), DOWN, (-1, 1), LEFT, (-1, -1)];
+
+pub fn add((x, y): Pos, (dx, dy): Pos) -> Pos {
+    (x + dx, y + dy)
+}
+
+/// The 4 orthogonal neighbours of `p`, whatever the bounds.
+pub fn neighbours_4(p: Pos) -> impl Iterator<Item = Pos> {
+    DIRECTIONS_4.into_iter().map(move |d| add(p, d))
+}
+
+/// The 8 surrounding positions of `p`, whatever the bounds.
+pub fn neighbours_8(p: Pos) -> impl Iterator<Item = Pos> {
+    DIRECTIONS_8.into_iter().map(move |d| add(p, d))
+}
+
+/// A grid of `T`, stored densely by default.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T, S = Dense<T>> {
+    storage: S,
+    cell: PhantomData<T>,
+}
+
+/// A grid storing only the positions that have a value.
+pub type SparseGrid<T> = Grid<T, Sparse<T>>;
+
+impl<T, S: Storage<T>> Grid<T, S> {
+    pub fn bounds(&self) -> Bounds {
+        self.storage.bounds()
+    }
+
+    pub fn get(&self, p: Pos) -> Option<&T> {
+        self.storage.get(p)
+    }
+
+    pub fn get_mut(&mut self, p: Pos) -> Option<&mut T> {
+        self.storage.get_mut(p)
+    }
+
+    pub fn contains(&self, p: Pos) -> bool {
+        self.storage.get(p).is_some()
+    }
+
+    /// Store `value` at `p`, returning the previous value.
+    pub fn insert(&mut self, p: Pos, value: T) -> Option<T> {
+        self.storage.insert(p, value)
+    }
+
+    /// Every cell with a value: row by row for a dense grid, in no particular order for a sparse one.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.storage.cells()
+    }
+
+    /// Orthogonal neighbours of `p` inside the bounds.
+    pub fn neighbours_4(&self, p: Pos) -> impl Iterator<Item = Pos> {
+        let bounds = self.bounds();
+        neighbours_4(p).filter(move |&n| bounds.contains(n))
+    }
+
+    /// Orthogonal and diagonal neighbours of `p` inside the bounds.
+    pub fn neighbours_8(&self, p: Pos) -> impl Iterator<Item = Pos> {
+        let bounds = self.bounds();
+        neighbours_8(p).filter(move |&n| bounds.contains(n))
+    }
+
+    /// Cells of row `y`, from left to right.
+    pub fn row(&self, y: i32) -> impl Iterator<Item = (Pos, &T)> {
+        let Bounds { min, max } = self.bounds();
+        (min.0..=max.0).filter_map(move |x| self.get((x, y)).map(|v| ((x, y), v)))
+    }
+
+    /// Cells of column `x`, from top to bottom.
+    pub fn column(&self, x: i32) -> impl Iterator<Item = (Pos, &T)> {
+        let Bounds { min, max } = self.bounds();
+        (min.1..=max.1).filter_map(move |y| self.get((x, y)).map(|v| ((x, y), v)))
+    }
+
+    /// Cells met walking from `from` (excluded) in `direction`, until leaving the bounds.
+    pub fn ray(&self, from: Pos, direction: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        let bounds = self.bounds();
+        std::iter::successors(Some(add(from, direction)), move |&p| {
+            Some(add(p, direction))
+        })
+        .take_while(move |&p| bounds.contains(p))
+        .filter_map(move |p| self.get(p).map(|v| (p, v)))
+    }
+
+    /// Draw the bounds of the grid row by row, with `draw` picking the character of each position.
+    pub fn render(&self, mut draw: impl FnMut(Pos, Option<&T>) -> char) -> String {
+        let Bounds { min, max } = self.bounds();
+        let mut out = String::new();
+        for y in min.1..=max.1 {
+            out.extend((min.0..=max.0).map(|x| draw((x, y), self.get((x, y)))));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl<T> Grid<T> {
+    /// Dense grid over `bounds`, every cell holding `value`.
+    pub fn new(bounds: Bounds, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_vec(bounds, vec![value; bounds.area()])
+    }
+
+    /// Dense grid over `bounds` from its cells, row by row.
+    ///
+    /// # Panics
+    ///
+    /// When there are not exactly as many cells as positions in `bounds`.
+    pub fn from_vec(bounds: Bounds, cells: Vec<T>) -> Grid<T> {
+        Grid {
+            storage: Dense::from_vec(bounds, cells),
+            cell: PhantomData,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.bounds().width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds().height()
+    }
+
+    /// Parse a rectangular character map, `(0, 0)` being its first character.
+    ///
+    /// `cell` maps each character to a value, characters it rejects are reported as
+    /// errors expecting `expected`. So are rows that are shorter or longer than the first one.
+    pub fn parse(
+        day: u8,
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(Pos, char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for line in lines(day, input) {
+            let y = line.number as i32 - 1;
+            let mut row_len = 0;
+            for (x, c) in line.text.chars().enumerate() {
+                let value = cell((x as i32, y), c)
+                    .ok_or_else(|| line.error(x + 1, expected, format!("`{}`", c)))?;
+                cells.push(value);
+                row_len += 1;
+            }
+            let width = *width.get_or_insert(row_len);
+            if row_len != width {
+                return Err(line.error(
+                    width.min(row_len) + 1,
+                    format!("a row of {} cells", width),
+                    format!("a row of {}", row_len),
+                ));
+            }
+            height += 1;
+        }
+        let width = width.unwrap_or(0);
+        if width == 0 {
+            return Err(ParseError::end_of_input(day, input, "a row of cells"));
+        }
+        Ok(Grid::from_vec(Bounds::sized(width, height), cells))
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        Grid {
+            storage: Sparse::new(),
+            cell: PhantomData,
+        }
+    }
+
+    /// Remove the value at `p`. The bounds do not shrink, see [`SparseGrid::shrink_bounds`].
+    pub fn remove(&mut self, p: Pos) -> Option<T> {
+        self.storage.remove(p)
+    }
+
+    /// Number of positions with a value.
+    pub fn len(&self) -> usize {
+        self.storage.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Reduce the bounds to the smallest rectangle holding every value.
+    pub fn shrink_bounds(&mut self) {
+        self.storage.shrink_bounds();
+    }
+
+    /// Parse a character map, `(0, 0)` being its first character. Rows may have any length.
+    ///
+    /// Characters in `empty` leave their position empty, the others are mapped by `cell`
+    /// and reported as errors expecting `expected` when it rejects them.
+    pub fn parse(
+        day: u8,
+        input: &str,
+        empty: &str,
+        expected: &str,
+        mut cell: impl FnMut(Pos, char) -> Option<T>,
+    ) -> Result<SparseGrid<T>, ParseError> {
+        let mut grid = SparseGrid::new();
+        for line in lines(day, input) {
+            let y = line.number as i32 - 1;
+            for (x, c) in line.text.chars().enumerate() {
+                if empty.contains(c) {
+                    continue;
+                }
+                let value = cell((x as i32, y), c)
+                    .ok_or_else(|| line.error(x + 1, expected, format!("`{}`", c)))?;
+                grid.insert((x as i32, y), value);
+            }
+        }
+        Ok(grid)
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid::new()
+    }
+}
+
+impl<T> FromIterator<(Pos, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Pos, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (p, value) in iter {
+            grid.insert(p, value);
+        }
+        grid
+    }
+}
+
+/// # Panics
+///
+/// When there is no value at the position.
+impl<T, S: Storage<T>> Index<Pos> for Grid<T, S> {
+    type Output = T;
+
+    fn index(&self, p: Pos) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("no cell at {:?} in {:?}", p, self.bounds()))
+    }
+}
+
+impl<T, S: Storage<T>> IndexMut<Pos> for Grid<T, S> {
+    fn index_mut(&mut self, p: Pos) -> &mut T {
+        let bounds = self.bounds();
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("no cell at {:?} in {:?}", p, bounds))
+    }
+}
+
+/// Each cell is drawn with its `Display` implementation, empty positions as `.`.
+impl<T: fmt::Display, S: Storage<T>> fmt::Display for Grid<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Bounds { min, max } = self.bounds();
+        for y in min.1..=max.1 {
+            for x in min.0..=max.0 {
+                match self.get((x, y)) {
+                    Some(v) => write!(f, "{}", v)?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
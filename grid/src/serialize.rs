@@ -0,0 +1,24 @@
+use crate::{Bounds, Dense, Grid, Pos, SparseGrid, Storage};
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+/// A dense grid is a list of rows, top to bottom.
+impl<T: Serialize> Serialize for Grid<T, Dense<T>> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let Bounds { min, max } = self.bounds();
+        let mut rows = serializer.serialize_seq(Some(self.height()))?;
+        for y in min.1..=max.1 {
+            let row: Vec<&T> = self.row(y).map(|(_, v)| v).collect();
+            rows.serialize_element(&row)?;
+        }
+        rows.end()
+    }
+}
+
+/// A sparse grid is a list of `[[x, y], value]` pairs, row by row.
+impl<T: Serialize> Serialize for SparseGrid<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut cells: Vec<(Pos, &T)> = self.storage.cells().collect();
+        cells.sort_unstable_by_key(|&((x, y), _)| (y, x));
+        cells.serialize(serializer)
+    }
+}
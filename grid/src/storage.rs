@@ -0,0 +1,111 @@
+use crate::{Bounds, Pos};
+use std::collections::HashMap;
+
+/// Backend of a [`Grid`](crate::Grid): where the cells live.
+pub trait Storage<T> {
+    /// Rectangle the cells are in.
+    fn bounds(&self) -> Bounds;
+    fn get(&self, p: Pos) -> Option<&T>;
+    fn get_mut(&mut self, p: Pos) -> Option<&mut T>;
+    /// Store `value` at `p`, returning the previous value.
+    fn insert(&mut self, p: Pos, value: T) -> Option<T>;
+    /// Every stored cell.
+    fn cells(&self) -> Box<dyn Iterator<Item = (Pos, &T)> + '_>;
+}
+
+/// A value for every position of a fixed rectangle, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dense<T> {
+    bounds: Bounds,
+    cells: Vec<T>,
+}
+
+impl<T> Dense<T> {
+    pub(crate) fn from_vec(bounds: Bounds, cells: Vec<T>) -> Dense<T> {
+        assert_eq!(bounds.area(), cells.len(), "cells do not fill the bounds");
+        Dense { bounds, cells }
+    }
+}
+
+impl<T> Storage<T> for Dense<T> {
+    fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    fn get(&self, p: Pos) -> Option<&T> {
+        self.bounds.index(p).map(|i| &self.cells[i])
+    }
+
+    fn get_mut(&mut self, p: Pos) -> Option<&mut T> {
+        self.bounds.index(p).map(|i| &mut self.cells[i])
+    }
+
+    /// # Panics
+    ///
+    /// A dense grid does not grow, `p` must be inside its bounds.
+    fn insert(&mut self, p: Pos, value: T) -> Option<T> {
+        let i = self
+            .bounds
+            .index(p)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid {:?}", p, self.bounds));
+        Some(std::mem::replace(&mut self.cells[i], value))
+    }
+
+    fn cells(&self) -> Box<dyn Iterator<Item = (Pos, &T)> + '_> {
+        Box::new(self.bounds.positions().zip(self.cells.iter()))
+    }
+}
+
+/// Values at some positions only, the bounds grow to hold every inserted position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sparse<T> {
+    bounds: Option<Bounds>,
+    cells: HashMap<Pos, T>,
+}
+
+impl<T> Sparse<T> {
+    pub(crate) fn new() -> Sparse<T> {
+        Sparse {
+            bounds: None,
+            cells: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn remove(&mut self, p: Pos) -> Option<T> {
+        self.cells.remove(&p)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub(crate) fn shrink_bounds(&mut self) {
+        self.bounds = self.cells.keys().fold(None, |b: Option<Bounds>, &p| {
+            Some(b.map_or(Bounds::point(p), |b| b.extend(p)))
+        });
+    }
+}
+
+impl<T> Storage<T> for Sparse<T> {
+    /// Empty bounds at the origin when nothing was ever inserted.
+    fn bounds(&self) -> Bounds {
+        self.bounds.unwrap_or(Bounds::new((0, 0), (-1, -1)))
+    }
+
+    fn get(&self, p: Pos) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    fn get_mut(&mut self, p: Pos) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+
+    fn insert(&mut self, p: Pos, value: T) -> Option<T> {
+        self.bounds = Some(self.bounds.map_or(Bounds::point(p), |b| b.extend(p)));
+        self.cells.insert(p, value)
+    }
+
+    fn cells(&self) -> Box<dyn Iterator<Item = (Pos, &T)> + '_> {
+        Box::new(self.cells.iter().map(|(&p, v)| (p, v)))
+    }
+}
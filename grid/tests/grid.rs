@@ -0,0 +1,143 @@
+use grid::{Bounds, Grid, SparseGrid, DOWN, LEFT, RIGHT, UP};
+
+fn digits(input: &str) -> Result<Grid<u32>, common::ParseError> {
+    Grid::<u32>::parse(8, input, "a digit", |_, c| c.to_digit(10))
+}
+
+#[test]
+fn parse() {
+    let grid = digits("123\n456\n").unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(0, 0)], 1);
+    assert_eq!(grid[(2, 1)], 6);
+    assert_eq!(grid.get((3, 0)), None);
+}
+
+#[test]
+fn parse_errors() {
+    let err = digits("123\n12\n456\n").unwrap_err();
+    assert_eq!((err.line_no, err.column), (2, 3));
+    assert_eq!(err.expected, "a row of 3 cells");
+    assert_eq!(err.found, "a row of 2");
+
+    let err = digits("123\n1234\n").unwrap_err();
+    assert_eq!((err.line_no, err.column), (2, 4));
+    assert_eq!(err.found, "a row of 4");
+
+    let err = digits("123\n\n456\n").unwrap_err();
+    assert_eq!((err.line_no, err.column), (2, 1));
+    assert_eq!(err.found, "a row of 0");
+
+    let err = digits("").unwrap_err();
+    assert_eq!((err.line_no, err.column), (1, 1));
+    assert_eq!(err.found, "end of input");
+
+    let err = digits("123\n4x6\n").unwrap_err();
+    assert_eq!((err.line_no, err.column), (2, 2));
+    assert_eq!(
+        (err.expected.as_str(), err.found.as_str()),
+        ("a digit", "`x`")
+    );
+}
+
+#[test]
+fn sparse_parse() {
+    let grid = SparseGrid::<()>::parse(14, "..#\n#\n", ".", "a rock", |_, c| {
+        (c == '#').then_some(())
+    })
+    .unwrap();
+    assert_eq!(grid.len(), 2);
+    assert_eq!(grid.bounds(), Bounds::new((0, 0), (2, 1)));
+    let err = SparseGrid::<()>::parse(14, "..#\n.o\n", ".", "a rock", |_, c| {
+        (c == '#').then_some(())
+    })
+    .unwrap_err();
+    assert_eq!((err.line_no, err.column), (2, 2));
+}
+
+#[test]
+fn lines_at_the_edges() {
+    let grid = digits("123\n456\n789\n").unwrap();
+    let values = |cells: Vec<(_, &u32)>| cells.into_iter().map(|(_, &v)| v).collect::<Vec<_>>();
+    assert_eq!(values(grid.row(2).collect()), [7, 8, 9]);
+    assert_eq!(values(grid.column(0).collect()), [1, 4, 7]);
+    assert_eq!(grid.row(3).count(), 0);
+    assert_eq!(grid.column(-1).count(), 0);
+    assert_eq!(values(grid.ray((1, 1), UP).collect()), [2]);
+    assert_eq!(values(grid.ray((0, 0), RIGHT).collect()), [2, 3]);
+    assert_eq!(grid.ray((0, 0), LEFT).count(), 0);
+    assert_eq!(grid.ray((2, 2), DOWN).count(), 0);
+    assert_eq!(values(grid.ray((0, 0), (1, 1)).collect()), [5, 9]);
+    // From outside of the bounds, the ray stops right away
+    assert_eq!(grid.ray((-2, 0), RIGHT).count(), 0);
+}
+
+#[test]
+fn negative_bounds() {
+    let grid: SparseGrid<char> = [((-2, -1), 'a'), ((0, -1), 'b'), ((-2, 1), 'c')]
+        .into_iter()
+        .collect();
+    assert_eq!(grid.bounds(), Bounds::new((-2, -1), (0, 1)));
+    let row: Vec<_> = grid.row(-1).collect();
+    assert_eq!(row, [((-2, -1), &'a'), ((0, -1), &'b')]);
+    let column: Vec<_> = grid.column(-2).collect();
+    assert_eq!(column, [((-2, -1), &'a'), ((-2, 1), &'c')]);
+    let ray: Vec<_> = grid.ray((-2, -2), DOWN).collect();
+    assert_eq!(ray, [((-2, -1), &'a'), ((-2, 1), &'c')]);
+    assert_eq!(grid.neighbours_4((-2, -1)).count(), 2);
+    assert_eq!(grid.neighbours_8((-1, 0)).count(), 8);
+}
+
+#[test]
+fn sparse_bounds() {
+    let mut grid = SparseGrid::new();
+    assert!(grid.is_empty());
+    assert!(grid.bounds().is_empty());
+    grid.insert((3, 4), 1);
+    assert_eq!(grid.bounds(), Bounds::point((3, 4)));
+    grid.insert((-1, 6), 2);
+    grid.insert((5, 0), 3);
+    assert_eq!(grid.bounds(), Bounds::new((-1, 0), (5, 6)));
+
+    // Removing does not shrink the bounds until asked to
+    assert_eq!(grid.remove((5, 0)), Some(3));
+    assert_eq!(grid.bounds(), Bounds::new((-1, 0), (5, 6)));
+    grid.shrink_bounds();
+    assert_eq!(grid.bounds(), Bounds::new((-1, 4), (3, 6)));
+    grid.remove((3, 4));
+    grid.remove((-1, 6));
+    grid.shrink_bounds();
+    assert!(grid.bounds().is_empty());
+}
+
+#[test]
+fn bounds_index() {
+    let bounds = Bounds::new((-1, -2), (1, 0));
+    assert_eq!((bounds.width(), bounds.height(), bounds.area()), (3, 3, 9));
+    assert_eq!(bounds.index((-1, -2)), Some(0));
+    assert_eq!(bounds.index((1, -2)), Some(2));
+    assert_eq!(bounds.index((-1, -1)), Some(3));
+    assert_eq!(bounds.index((1, 0)), Some(8));
+    assert_eq!(bounds.index((2, 0)), None);
+    assert_eq!(bounds.index((0, -3)), None);
+    let indices: Vec<usize> = bounds.positions().filter_map(|p| bounds.index(p)).collect();
+    assert_eq!(indices, (0..9).collect::<Vec<_>>());
+    assert_eq!(Bounds::sized(0, 3).index((0, 0)), None);
+}
+
+#[test]
+fn display() {
+    let grid = digits("12\n34\n").unwrap();
+    assert_eq!(grid.to_string(), "12\n34\n");
+    let sparse: SparseGrid<char> = [((-1, 0), '#'), ((1, 1), 'o')].into_iter().collect();
+    assert_eq!(sparse.to_string(), "#..\n..o\n");
+    assert_eq!(SparseGrid::<char>::new().to_string(), "");
+    let rendered = grid.render(|_, v| {
+        if v.is_some_and(|&v| v % 2 == 0) {
+            '#'
+        } else {
+            '.'
+        }
+    });
+    assert_eq!(rendered, ".#\n.#\n");
+}
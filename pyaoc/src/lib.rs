@@ -0,0 +1,162 @@
+//! Python bindings: the parsed puzzle inputs as plain Python values, and the solvers.
+//!
+//! Parse results go through their `serde` serialization: structs become dicts, enums
+//! tagged dicts or strings, dense grids lists of rows and sparse grids `[[x, y], value]`
+//! pairs.
+
+use common::{Answer, ParseError, Solution, SolveError};
+use pyo3::create_exception;
+use pyo3::exceptions::{PyOverflowError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use serde::Serialize;
+use serde_json::Value;
+
+create_exception!(
+    pyaoc,
+    InputError,
+    PyValueError,
+    "A puzzle input the day cannot parse."
+);
+
+type Parser = fn(&str) -> Result<Value, ParseError>;
+
+fn parser<S: Solution>() -> (u8, Parser)
+where
+    S::Input: Serialize,
+{
+    (S::DAY, |input| {
+        let parsed = S::parse(input)?;
+        Ok(serde_json::to_value(parsed).expect("parse results serialize to JSON"))
+    })
+}
+
+fn parsers() -> [(u8, Parser); 25] {
+    [
+        parser::<day01::Day01>(),
+        parser::<day02::Day02>(),
+        parser::<day03::Day03>(),
+        parser::<day04::Day04>(),
+        parser::<day05::Day05>(),
+        parser::<day06::Day06>(),
+        parser::<day07::Day07>(),
+        parser::<day08::Day08>(),
+        parser::<day09::Day09>(),
+        parser::<day10::Day10>(),
+        parser::<day11::Day11>(),
+        parser::<day12::Day12>(),
+        parser::<day13::Day13>(),
+        parser::<day14::Day14>(),
+        parser::<day15::Day15>(),
+        parser::<day16::Day16>(),
+        parser::<day17::Day17>(),
+        parser::<day18::Day18>(),
+        parser::<day19::Day19>(),
+        parser::<day20::Day20>(),
+        parser::<day21::Day21>(),
+        parser::<day22::Day22>(),
+        parser::<day23::Day23>(),
+        parser::<day24::Day24>(),
+        parser::<day25::Day25>(),
+    ]
+}
+
+/// The parse result of `day` on `input` as JSON, `None` when there is no such day.
+pub fn parse_json(day: u8, input: &str) -> Option<Result<Value, ParseError>> {
+    let (_, parse) = parsers().into_iter().find(|&(d, _)| d == day)?;
+    Some(parse(input))
+}
+
+fn input_error(e: ParseError) -> PyErr {
+    InputError::new_err(e.to_string())
+}
+
+fn to_python<'py>(py: Python<'py>, value: &Value) -> PyResult<Bound<'py, PyAny>> {
+    Ok(match value {
+        Value::Null => py.None().into_bound(py),
+        Value::Bool(b) => b.into_pyobject(py)?.to_owned().into_any(),
+        Value::Number(n) => match (n.as_i64(), n.as_u64()) {
+            (Some(i), _) => i.into_pyobject(py)?.into_any(),
+            (_, Some(u)) => u.into_pyobject(py)?.into_any(),
+            _ => n.as_f64().into_pyobject(py)?.into_any(),
+        },
+        Value::String(s) => s.into_pyobject(py)?.into_any(),
+        Value::Array(values) => {
+            let list = PyList::empty(py);
+            for v in values {
+                list.append(to_python(py, v)?)?;
+            }
+            list.into_any()
+        }
+        Value::Object(fields) => {
+            let dict = PyDict::new(py);
+            for (k, v) in fields {
+                dict.set_item(k, to_python(py, v)?)?;
+            }
+            dict.into_any()
+        }
+    })
+}
+
+fn answer_to_python<'py>(py: Python<'py>, answer: Answer) -> PyResult<Bound<'py, PyAny>> {
+    Ok(match answer {
+        Answer::Int(v) => v.into_pyobject(py)?.into_any(),
+        Answer::UInt(v) => v.into_pyobject(py)?.into_any(),
+        Answer::Text(v) => v.into_pyobject(py)?.into_any(),
+    })
+}
+
+fn solve_part(py: Python<'_>, day: u8, part: u8, input: &str) -> PyResult<Py<PyAny>> {
+    let solver = aoc::day(day).ok_or_else(|| PyValueError::new_err(format!("no day {}", day)))?;
+    if part > solver.parts {
+        return Err(PyValueError::new_err(format!(
+            "day {} has no part {}",
+            day, part
+        )));
+    }
+    let mut answers = py
+        .detach(|| (solver.solve)(input, &[part]))
+        .map_err(|err| match err {
+            SolveError::Parse(err) => input_error(err),
+            SolveError::Overflow(err) => PyOverflowError::new_err(err.to_string()),
+        })?;
+    Ok(answer_to_python(py, answers.remove(0).answer)?.unbind())
+}
+
+/// The numbers of the days, in order.
+#[pyfunction]
+fn days() -> Vec<u32> {
+    // A `Vec<u8>` would be converted to `bytes`
+    aoc::days().iter().map(|d| d.number as u32).collect()
+}
+
+/// The puzzle input of `day`, parsed as the solvers see it.
+#[pyfunction]
+fn parse(py: Python<'_>, day: u8, input: &str) -> PyResult<Py<PyAny>> {
+    let parsed = parse_json(day, input)
+        .ok_or_else(|| PyValueError::new_err(format!("no day {}", day)))?
+        .map_err(input_error)?;
+    Ok(to_python(py, &parsed)?.unbind())
+}
+
+/// The answer to part 1 of `day` on `input`.
+#[pyfunction]
+fn part1(py: Python<'_>, day: u8, input: &str) -> PyResult<Py<PyAny>> {
+    solve_part(py, day, 1, input)
+}
+
+/// The answer to part 2 of `day` on `input`.
+#[pyfunction]
+fn part2(py: Python<'_>, day: u8, input: &str) -> PyResult<Py<PyAny>> {
+    solve_part(py, day, 2, input)
+}
+
+#[pymodule]
+fn pyaoc(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("InputError", m.py().get_type::<InputError>())?;
+    m.add_function(wrap_pyfunction!(days, m)?)?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_function(wrap_pyfunction!(part1, m)?)?;
+    m.add_function(wrap_pyfunction!(part2, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use serde_json::json;
+
+fn example(day: u8) -> String {
+    let path = format!(
+        "{}/../day{:02}/data.test.txt",
+        env!("CARGO_MANIFEST_DIR"),
+        day
+    );
+    std::fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn packets_are_nested_lists() {
+    let packets = pyaoc::parse_json(13, &example(13)).unwrap().unwrap();
+    assert_eq!(packets[1], json!([[[1], [2, 3, 4]], [[1], 4]]));
+}
+
+#[test]
+fn dense_grids_are_rows() {
+    let forest = pyaoc::parse_json(8, &example(8)).unwrap().unwrap();
+    assert_eq!(forest[0], json!([3, 0, 3, 7, 3]));
+    assert_eq!(forest.as_array().unwrap().len(), 5);
+}
+
+#[test]
+fn sparse_grids_are_sorted_cells() {
+    let elves = pyaoc::parse_json(23, &example(23)).unwrap().unwrap();
+    assert_eq!(elves[0], json!([[4, 0], null]));
+    assert_eq!(elves[1], json!([[2, 1], null]));
+}
+
+#[test]
+fn errors_and_unknown_days() {
+    let e = pyaoc::parse_json(1, "1000\nx\n").unwrap().unwrap_err();
+    assert_eq!((e.line_no, e.column), (2, 1));
+    assert!(pyaoc::parse_json(26, "").is_none());
+}
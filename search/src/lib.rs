@@ -0,0 +1,156 @@
+//! Shortest path searches over implicit graphs.
+//!
+//! The graph is never built: a search starts from a state and asks a `neighbours` closure
+//! for the states reachable from the current one, until a `goal` predicate accepts a state.
+
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A path found by a search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    /// Total cost of the path, the number of steps for [`bfs`].
+    pub cost: C,
+    /// Every state from the start to the goal, both included.
+    pub states: Vec<S>,
+}
+
+/// Breadth first search, every step costing 1.
+///
+/// Returns the shortest path from `start` to the first state accepted by `goal`, or `None`
+/// when no such state is reachable.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut parents = HashMap::from([(start.clone(), None)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, steps)) = queue.pop_front() {
+        if goal(&state) {
+            return Some(Path {
+                cost: steps,
+                states: reconstruct(&parents, state),
+            });
+        }
+        for next in neighbours(&state) {
+            if let Entry::Vacant(entry) = parents.entry(next.clone()) {
+                entry.insert(Some(state.clone()));
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Dijkstra's algorithm, `neighbours` giving each reachable state with the cost of the step.
+///
+/// Costs must not be negative, `C::default()` being the zero cost.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, neighbours, |_| C::default(), goal)
+}
+
+/// A* search: Dijkstra's algorithm visiting first the states that `heuristic` estimates
+/// closer to the goal.
+///
+/// The heuristic must never overestimate the remaining cost, or the path may not be the
+/// shortest one.
+pub fn astar<S, C, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let zero = C::default();
+    let mut best = HashMap::from([(start.clone(), (zero, None))]);
+    let mut parents = HashMap::new();
+    let mut queue = BinaryHeap::from([Queued {
+        priority: heuristic(&start),
+        cost: zero,
+        state: start,
+    }]);
+    while let Some(Queued { cost, state, .. }) = queue.pop() {
+        let (best_cost, parent) = &best[&state];
+        if cost > *best_cost {
+            continue;
+        }
+        // The cost of a state is final once it leaves the queue
+        parents.insert(state.clone(), parent.clone());
+        if goal(&state) {
+            return Some(Path {
+                cost,
+                states: reconstruct(&parents, state),
+            });
+        }
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            if best.get(&next).is_none_or(|&(c, _)| next_cost < c) {
+                best.insert(next.clone(), (next_cost, Some(state.clone())));
+                queue.push(Queued {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// Walk the parents back from `goal` to the start, the only state without a parent.
+fn reconstruct<S: Clone + Eq + Hash>(parents: &HashMap<S, Option<S>>, goal: S) -> Vec<S> {
+    let mut states = vec![goal];
+    while let Some(Some(parent)) = parents.get(states.last().unwrap()) {
+        states.push(parent.clone());
+    }
+    states.reverse();
+    states
+}
+
+/// A state waiting in the A* queue, the lowest priority first.
+struct Queued<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
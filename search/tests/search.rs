@@ -0,0 +1,103 @@
+use search::{astar, bfs, dijkstra, Path};
+
+/// Open cells of a small maze, `#` being walls.
+const MAZE: [&str; 4] = ["S..#", ".#.#", ".#..", "...G"];
+
+fn open((x, y): (i32, i32)) -> bool {
+    MAZE.get(y as usize)
+        .and_then(|row| row.as_bytes().get(x as usize))
+        .is_some_and(|&c| c != b'#')
+}
+
+fn steps((x, y): &(i32, i32)) -> Vec<(i32, i32)> {
+    [(x + 1, *y), (x - 1, *y), (*x, y + 1), (*x, y - 1)]
+        .into_iter()
+        .filter(|&p| open(p))
+        .collect()
+}
+
+/// Is every state of `path` one step from the previous one?
+fn connected(path: &[(i32, i32)]) -> bool {
+    path.windows(2)
+        .all(|w| (w[0].0 - w[1].0).abs() + (w[0].1 - w[1].1).abs() == 1)
+}
+
+#[test]
+fn bfs_path() {
+    let path = bfs((0, 0), steps, |&p| p == (3, 3)).unwrap();
+    assert_eq!(path.cost, 6);
+    assert_eq!(path.states.len(), 7);
+    assert_eq!(path.states.first(), Some(&(0, 0)));
+    assert_eq!(path.states.last(), Some(&(3, 3)));
+    assert!(connected(&path.states));
+}
+
+#[test]
+fn bfs_states_of_a_line() {
+    let path = bfs(0, |&n| [n + 1], |&n| n == 4).unwrap();
+    assert_eq!(
+        path,
+        Path {
+            cost: 4,
+            states: vec![0, 1, 2, 3, 4]
+        }
+    );
+}
+
+#[test]
+fn start_is_goal() {
+    let expected = Path {
+        cost: 0,
+        states: vec![(0, 0)],
+    };
+    assert_eq!(bfs((0, 0), steps, |&p| p == (0, 0)), Some(expected.clone()));
+    let weighted = |p: &(i32, i32)| steps(p).into_iter().map(|n| (n, 1));
+    assert_eq!(
+        dijkstra((0, 0), weighted, |&p| p == (0, 0)),
+        Some(expected.clone())
+    );
+    assert_eq!(
+        astar((0, 0), weighted, |_| 0, |&p| p == (0, 0)),
+        Some(expected)
+    );
+}
+
+#[test]
+fn unreachable_goal() {
+    assert_eq!(bfs((0, 0), steps, |&p| p == (3, 0)), None);
+    let weighted = |p: &(i32, i32)| steps(p).into_iter().map(|n| (n, 1u32));
+    assert_eq!(dijkstra((0, 0), weighted, |&p| p == (3, 0)), None);
+    assert_eq!(astar((0, 0), weighted, |_| 0, |&p| p == (3, 0)), None);
+}
+
+#[test]
+fn dijkstra_replaces_a_parent() {
+    // `c` is first reached straight from `a`, then for less through `b`
+    let edges = |s: &char| match s {
+        'a' => vec![('c', 5), ('b', 1)],
+        'b' => vec![('c', 1)],
+        'c' => vec![('d', 1)],
+        _ => vec![],
+    };
+    let path = dijkstra('a', edges, |&s| s == 'd').unwrap();
+    assert_eq!(
+        path,
+        Path {
+            cost: 3,
+            states: vec!['a', 'b', 'c', 'd']
+        }
+    );
+}
+
+#[test]
+fn astar_path() {
+    let weighted = |p: &(i32, i32)| steps(p).into_iter().map(|n| (n, 1));
+    let manhattan = |&(x, y): &(i32, i32)| (3 - x).abs() + (3 - y).abs();
+    let path = astar((0, 0), weighted, manhattan, |&p| p == (3, 3)).unwrap();
+    let shortest = bfs((0, 0), steps, |&p| p == (3, 3)).unwrap();
+    assert_eq!(path.cost as usize, shortest.cost);
+    assert_eq!(path.states.len(), shortest.states.len());
+    assert_eq!(path.states.first(), Some(&(0, 0)));
+    assert_eq!(path.states.last(), Some(&(3, 3)));
+    assert!(connected(&path.states));
+}
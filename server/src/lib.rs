@@ -0,0 +1,65 @@
+//! HTTP API over the solvers. `POST /days/{day}/parts/{part}` with a puzzle input as the
+//! body answers with JSON such as `{"day":1,"part":2,"answer":45000,"elapsed_ns":1834}`.
+//!
+//! Errors come as `{"error": "..."}`: 404 for an unknown day or part, 422 for an input the
+//! parser rejects (with its `line` and `column`) or too large for the solver's integer types,
+//! 500 if the solver panics.
+
+use aoc::report::answer_json;
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::{Json, Router};
+use common::SolveError;
+use serde_json::json;
+
+pub fn app() -> Router {
+    Router::new().route("/days/{day}/parts/{part}", post(solve))
+}
+
+fn error(status: StatusCode, message: String) -> Response {
+    (status, Json(json!({ "error": message }))).into_response()
+}
+
+async fn solve(Path((number, part)): Path<(u8, u8)>, input: String) -> Response {
+    let Some(day) = aoc::day(number) else {
+        return error(
+            StatusCode::NOT_FOUND,
+            format!("no solution for day {}", number),
+        );
+    };
+    if part == 0 || part > day.parts {
+        return error(
+            StatusCode::NOT_FOUND,
+            format!("day {} has no part {}", number, part),
+        );
+    }
+    // Some parts take seconds, keep them off the async workers
+    let solved = tokio::task::spawn_blocking(move || (day.solve)(&input, &[part])).await;
+    match solved {
+        Ok(Ok(answers)) => Json(json!({
+            "day": number,
+            "part": part,
+            "answer": answer_json(&answers[0].answer),
+            "elapsed_ns": answers[0].elapsed.as_nanos() as u64,
+        }))
+        .into_response(),
+        Ok(Err(SolveError::Parse(err))) => (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(json!({
+                "error": err.to_string(),
+                "line": err.line_no,
+                "column": err.column,
+            })),
+        )
+            .into_response(),
+        Ok(Err(SolveError::Overflow(err))) => {
+            error(StatusCode::UNPROCESSABLE_ENTITY, err.to_string())
+        }
+        Err(_) => error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("day {} part {} failed on this input", number, part),
+        ),
+    }
+}
@@ -0,0 +1,31 @@
+use clap::Parser;
+use std::net::SocketAddr;
+use std::process;
+
+#[derive(Parser)]
+#[command(
+    name = "aoc-server",
+    about = "HTTP API over the Advent of Code 2022 solutions"
+)]
+struct Cli {
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:3000")]
+    addr: SocketAddr,
+}
+
+#[tokio::main]
+async fn main() {
+    let cli = Cli::parse();
+    let listener = match tokio::net::TcpListener::bind(cli.addr).await {
+        Ok(listener) => listener,
+        Err(err) => {
+            eprintln!("error: {}: {}", cli.addr, err);
+            process::exit(1);
+        }
+    };
+    println!("listening on http://{}", cli.addr);
+    if let Err(err) = axum::serve(listener, server::app()).await {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
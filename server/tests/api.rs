@@ -0,0 +1,59 @@
+//! Requests sent straight to the router, without opening a socket.
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use http_body_util::BodyExt;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use tower::ServiceExt;
+
+fn example(day: u8) -> String {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    fs::read_to_string(root.join(format!("day{:02}/data.test.txt", day))).unwrap()
+}
+
+async fn post(uri: &str, body: impl Into<Body>) -> (StatusCode, Value) {
+    let request = Request::post(uri).body(body.into()).unwrap();
+    let response = server::app().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+#[tokio::test]
+async fn solves_a_part() {
+    let (status, body) = post("/days/1/parts/2", example(1)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["day"], 1);
+    assert_eq!(body["part"], 2);
+    assert_eq!(body["answer"], 45000);
+    assert!(body["elapsed_ns"].is_u64());
+}
+
+#[tokio::test]
+async fn text_answers_are_strings() {
+    let (status, body) = post("/days/10/parts/2", example(10)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["answer"].as_str().unwrap().starts_with("##  ##  ##"));
+}
+
+#[tokio::test]
+async fn unknown_days_and_parts_are_not_found() {
+    let (status, body) = post("/days/26/parts/1", "").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"], "no solution for day 26");
+
+    let (status, body) = post("/days/25/parts/2", example(25)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"], "day 25 has no part 2");
+}
+
+#[tokio::test]
+async fn parse_errors_point_at_the_input() {
+    let (status, body) = post("/days/1/parts/1", "1000\n2000\nlots\n").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["line"], 3);
+    assert_eq!(body["column"], 1);
+    assert!(body["error"].as_str().unwrap().contains("calorie count"));
+}
@@ -0,0 +1,178 @@
+//! Frame by frame replays of the simulation days.
+//!
+//! A day implementing [`Visualize`] hands a [`Frame`] to a callback after each step of its
+//! simulation. [`record`] writes those frames as an ASCII stream, PPM or PNG image sequences,
+//! or an animated GIF, picked from the extension of the output file.
+
+use common::{ParseError, Solution};
+use grid::{Bounds, Grid, Pos, Storage};
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+mod output;
+
+use output::Canvas;
+
+/// A picture of the simulation, one character per cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// Position of the top left character, frames are laid out on a shared canvas.
+    origin: Pos,
+    width: usize,
+    cells: Vec<char>,
+}
+
+impl Frame {
+    /// Frame of a multi-line text, shorter lines padded with spaces.
+    pub fn new(origin: Pos, text: &str) -> Frame {
+        let width = text.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+        let mut cells = Vec::new();
+        for line in text.lines() {
+            let len = cells.len();
+            cells.extend(line.chars());
+            cells.resize(len + width, ' ');
+        }
+        Frame {
+            origin,
+            width,
+            cells,
+        }
+    }
+
+    /// Frame of the bounds of `grid`, `draw` picking the character of each position.
+    pub fn from_grid<T, S: Storage<T>>(
+        grid: &Grid<T, S>,
+        draw: impl FnMut(Pos, Option<&T>) -> char,
+    ) -> Frame {
+        Frame::new(grid.bounds().min, &grid.render(draw))
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        let height = self.cells.len().checked_div(self.width).unwrap_or(0);
+        Bounds::new(
+            self.origin,
+            (
+                self.origin.0 + self.width as i32 - 1,
+                self.origin.1 + height as i32 - 1,
+            ),
+        )
+    }
+
+    /// Character at `p`, if it is inside the frame.
+    pub fn get(&self, p: Pos) -> Option<char> {
+        self.bounds().index(p).map(|i| self.cells[i])
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.cells.chunks(self.width.max(1)) {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+/// Callback receiving the frames of a simulation.
+pub type Frames<'a> = dyn FnMut(&dyn Fn() -> Frame) + 'a;
+
+/// A day whose simulation can be replayed frame by frame.
+pub trait Visualize: Solution {
+    /// Run the simulation of `part`, calling `frame` after every step with a closure
+    /// drawing it. Frames that are skipped are never drawn.
+    fn visualize(input: &Self::Input, part: u8, frame: &mut Frames);
+
+    /// Colour of a character in the image outputs.
+    fn color(c: char) -> [u8; 3] {
+        output::default_color(c)
+    }
+}
+
+/// Type-erased handle on a [`Visualize`] day, like [`common::Day`] for solutions.
+#[derive(Clone, Copy)]
+pub struct Visualizer {
+    pub day: u8,
+    pub run: fn(&str, u8, &mut Frames) -> Result<(), ParseError>,
+    pub color: fn(char) -> [u8; 3],
+}
+
+impl Visualizer {
+    pub fn of<V: Visualize>() -> Visualizer {
+        Visualizer {
+            day: V::DAY,
+            run: |input, part, frame| {
+                V::visualize(&V::parse(input)?, part, frame);
+                Ok(())
+            },
+            color: V::color,
+        }
+    }
+}
+
+/// How to write the frames.
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    /// Keep one frame out of `every`.
+    pub every: usize,
+    /// Size in pixels of a character in the image outputs.
+    pub scale: usize,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Parse(ParseError),
+    Io(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+/// Replay `part` of the day on `input` into `path`, returning the number of frames written.
+///
+/// The simulation runs twice: first to size the canvas to every frame, then to write them.
+pub fn record(
+    visualizer: &Visualizer,
+    input: &str,
+    part: u8,
+    path: &Path,
+    options: &Options,
+) -> Result<usize, Error> {
+    let every = options.every.max(1);
+    let mut bounds: Option<Bounds> = None;
+    let mut count = 0;
+    (visualizer.run)(input, part, &mut |draw| {
+        let b = match count % every {
+            0 => draw().bounds(),
+            _ => Bounds::point((0, 0)),
+        };
+        if count % every == 0 && !b.is_empty() {
+            bounds = Some(bounds.map_or(b, |bounds| bounds.extend(b.min).extend(b.max)));
+        }
+        count += 1;
+    })
+    .map_err(Error::Parse)?;
+    let canvas = Canvas {
+        bounds: bounds.unwrap_or(Bounds::point((0, 0))),
+        scale: options.scale.max(1),
+        color: visualizer.color,
+    };
+    let mut sink = output::open(path, canvas)?;
+    let mut written = 0;
+    let mut result = Ok(());
+    count = 0;
+    (visualizer.run)(input, part, &mut |draw| {
+        if count % every == 0 && result.is_ok() {
+            result = sink.frame(&draw());
+            written += 1;
+        }
+        count += 1;
+    })
+    .map_err(Error::Parse)?;
+    result?;
+    sink.finish()?;
+    Ok(written)
+}
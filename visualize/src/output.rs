@@ -0,0 +1,224 @@
+use crate::Frame;
+use grid::Bounds;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// Area shared by every frame of an image output, and how to draw it.
+#[derive(Clone, Copy)]
+pub(crate) struct Canvas {
+    pub bounds: Bounds,
+    /// Size in pixels of a character.
+    pub scale: usize,
+    pub color: fn(char) -> [u8; 3],
+}
+
+pub(crate) fn default_color(c: char) -> [u8; 3] {
+    const BRIGHT: [[u8; 3]; 6] = [
+        [230, 90, 80],
+        [110, 200, 110],
+        [90, 150, 230],
+        [230, 200, 90],
+        [200, 110, 220],
+        [90, 210, 210],
+    ];
+    match c {
+        ' ' | '.' => [20, 20, 30],
+        '#' => [140, 140, 150],
+        'o' => [230, 190, 90],
+        c => BRIGHT[c as usize % BRIGHT.len()],
+    }
+}
+
+/// A frame drawn on the canvas: a palette and one palette index per pixel.
+struct Image {
+    width: usize,
+    height: usize,
+    palette: Vec<[u8; 3]>,
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    fn draw(canvas: &Canvas, frame: &Frame) -> Image {
+        let mut chars = vec![' '];
+        let mut indices = Vec::with_capacity(canvas.bounds.area());
+        for p in canvas.bounds.positions() {
+            let c = frame.get(p).unwrap_or(' ');
+            let index = match chars.iter().position(|&known| known == c) {
+                Some(index) => index,
+                None if chars.len() < 256 => {
+                    chars.push(c);
+                    chars.len() - 1
+                }
+                // Out of colours, the GIF palette stops at 256
+                None => 0,
+            };
+            indices.push(index as u8);
+        }
+        let scale = canvas.scale;
+        let width = canvas.bounds.width() * scale;
+        let mut pixels = Vec::with_capacity(width * canvas.bounds.height() * scale);
+        for row in indices.chunks(canvas.bounds.width()) {
+            let line: Vec<u8> = row
+                .iter()
+                .flat_map(|&i| std::iter::repeat_n(i, scale))
+                .collect();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        Image {
+            width,
+            height: canvas.bounds.height() * scale,
+            palette: chars.into_iter().map(canvas.color).collect(),
+            pixels,
+        }
+    }
+
+    fn rgb(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|&i| self.palette[i as usize])
+            .collect()
+    }
+}
+
+/// Destination of the frames.
+pub(crate) trait Sink {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()>;
+    fn finish(self: Box<Self>) -> io::Result<()>;
+}
+
+/// Pick the output from the extension of `path`: `gif`, `png` or `ppm`, any other being
+/// an ASCII stream. `-` streams ASCII to the standard output.
+pub(crate) fn open(path: &Path, canvas: Canvas) -> io::Result<Box<dyn Sink>> {
+    if path == Path::new("-") {
+        return Ok(Box::new(Ascii(Box::new(io::stdout().lock()))));
+    }
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("gif") => Ok(Box::new(Gif::create(path, canvas)?)),
+        Some("png") => Ok(Box::new(Sequence::new(path, canvas, write_png))),
+        Some("ppm") => Ok(Box::new(Sequence::new(path, canvas, write_ppm))),
+        _ => Ok(Box::new(Ascii(Box::new(BufWriter::new(File::create(
+            path,
+        )?))))),
+    }
+}
+
+/// Every frame as text, followed by an empty line.
+struct Ascii(Box<dyn Write>);
+
+impl Sink for Ascii {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        writeln!(self.0, "{}", frame)
+    }
+
+    fn finish(mut self: Box<Self>) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
+/// One image file per frame, numbered after the name of the output: `out.png` gives
+/// `out-0000.png`, `out-0001.png`...
+struct Sequence {
+    path: PathBuf,
+    canvas: Canvas,
+    count: usize,
+    write: fn(&Path, &Image) -> io::Result<()>,
+}
+
+impl Sequence {
+    fn new(path: &Path, canvas: Canvas, write: fn(&Path, &Image) -> io::Result<()>) -> Sequence {
+        Sequence {
+            path: path.to_path_buf(),
+            canvas,
+            count: 0,
+            write,
+        }
+    }
+}
+
+impl Sink for Sequence {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        let stem = self.path.file_stem().unwrap_or_default().to_string_lossy();
+        let extension = self.path.extension().unwrap_or_default().to_string_lossy();
+        let path = self
+            .path
+            .with_file_name(format!("{}-{:04}.{}", stem, self.count, extension));
+        (self.write)(&path, &Image::draw(&self.canvas, frame))?;
+        self.count += 1;
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn write_ppm(path: &Path, image: &Image) -> io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    write!(out, "P6\n{} {}\n255\n", image.width, image.height)?;
+    out.write_all(&image.rgb())?;
+    out.flush()
+}
+
+fn write_png(path: &Path, image: &Image) -> io::Result<()> {
+    let out = BufWriter::new(File::create(path)?);
+    let mut encoder = png::Encoder::new(out, image.width as u32, image.height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    writer
+        .write_image_data(&image.rgb())
+        .map_err(io::Error::other)
+}
+
+/// An animated GIF looping over the frames.
+struct Gif {
+    encoder: gif::Encoder<BufWriter<File>>,
+    canvas: Canvas,
+}
+
+impl Gif {
+    /// Delay between two frames, in hundredths of a second.
+    const DELAY: u16 = 5;
+
+    fn create(path: &Path, canvas: Canvas) -> io::Result<Gif> {
+        let size = |len: usize| {
+            u16::try_from(len * canvas.scale).map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "the frames are too large for a GIF, lower the scale",
+                )
+            })
+        };
+        let (width, height) = (size(canvas.bounds.width())?, size(canvas.bounds.height())?);
+        let out = BufWriter::new(File::create(path)?);
+        let mut encoder = gif::Encoder::new(out, width, height, &[]).map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+        Ok(Gif { encoder, canvas })
+    }
+}
+
+impl Sink for Gif {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        let image = Image::draw(&self.canvas, frame);
+        let palette: Vec<u8> = image.palette.iter().flatten().copied().collect();
+        let mut frame = gif::Frame::from_palette_pixels(
+            image.width as u16,
+            image.height as u16,
+            image.pixels,
+            palette,
+            None,
+        );
+        frame.delay = Gif::DELAY;
+        self.encoder.write_frame(&frame).map_err(io::Error::other)
+    }
+
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        let mut out = self.encoder.into_inner().map_err(io::Error::other)?;
+        out.flush()
+    }
+}
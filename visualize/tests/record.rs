@@ -0,0 +1,102 @@
+use common::{Answer, ParseError, Solution};
+use grid::Bounds;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use visualize::{Error, Frame, Frames, Options, Visualize, Visualizer};
+
+/// A simulation of `n` steps: frame `i` is a row of `i + 1` cells, except for frame 1, a
+/// block far larger than the others. Part 2 draws a single frame 20000 cells wide.
+struct Steps;
+
+impl Solution for Steps {
+    const DAY: u8 = 1;
+
+    type Input = usize;
+
+    fn parse(input: &str) -> Result<usize, ParseError> {
+        Ok(input.trim().parse().unwrap())
+    }
+
+    fn part1(_: &usize) -> Answer {
+        0u32.into()
+    }
+
+    fn part2(_: &usize) -> Answer {
+        0u32.into()
+    }
+}
+
+impl Visualize for Steps {
+    fn visualize(&n: &usize, part: u8, frame: &mut Frames) {
+        if part == 2 {
+            return frame(&|| Frame::new((0, 0), &"#".repeat(20000)));
+        }
+        for i in 0..n {
+            let text = match i {
+                1 => format!("{}\n", "o".repeat(50)).repeat(3),
+                i => "#".repeat(i + 1),
+            };
+            frame(&|| Frame::new((0, 0), &text));
+        }
+    }
+}
+
+fn output(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("visualize-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(name)
+}
+
+#[test]
+fn frame_padding() {
+    let frame = Frame::new((2, -1), "ab\nc\n\ndef");
+    assert_eq!(frame.bounds(), Bounds::new((2, -1), (4, 2)));
+    assert_eq!(frame.to_string(), "ab \nc  \n   \ndef\n");
+    assert_eq!(frame.get((2, -1)), Some('a'));
+    assert_eq!(frame.get((4, 0)), Some(' '));
+    assert_eq!(frame.get((4, 2)), Some('f'));
+    assert_eq!(frame.get((1, 0)), None);
+    assert_eq!(frame.get((2, 3)), None);
+    assert!(Frame::new((0, 0), "").bounds().is_empty());
+}
+
+#[test]
+fn record_every_other_frame() {
+    let visualizer = Visualizer::of::<Steps>();
+    let options = Options { every: 2, scale: 2 };
+    let path = output("steps.ppm");
+    let written = visualize::record(&visualizer, "5", 1, &path, &options).unwrap();
+    assert_eq!(written, 3);
+    // Frames 0, 2 and 4 are kept: the canvas fits the last one, 5 cells wide, not frame 1
+    for i in 0..3 {
+        let image = fs::read(output(&format!("steps-{:04}.ppm", i))).unwrap();
+        assert!(image.starts_with(b"P6\n10 2\n255\n"));
+        assert_eq!(image.len(), b"P6\n10 2\n255\n".len() + 10 * 2 * 3);
+    }
+    assert!(!output("steps-0003.ppm").exists());
+
+    let path = output("steps.txt");
+    let written = visualize::record(&visualizer, "5", 1, &path, &options).unwrap();
+    assert_eq!(written, 3);
+    assert_eq!(fs::read_to_string(&path).unwrap(), "#\n\n###\n\n#####\n\n");
+}
+
+#[test]
+fn gif_too_large() {
+    let visualizer = Visualizer::of::<Steps>();
+    let options = Options { every: 1, scale: 4 };
+    let path = output("wide.gif");
+    match visualize::record(&visualizer, "1", 2, &path, &options) {
+        Err(Error::Io(err)) => {
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+            assert!(err.to_string().contains("lower the scale"), "{}", err);
+        }
+        other => panic!("expected a size error, got {:?}", other.map(|_| ())),
+    }
+    let options = Options { every: 1, scale: 1 };
+    assert_eq!(
+        visualize::record(&visualizer, "1", 2, &path, &options).unwrap(),
+        1
+    );
+}